@@ -0,0 +1,20 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door01;
+
+impl Generator for Door01 {
+    const SIZE : usize = 2000;
+
+    /// Writes `size` depths which, like the sea floor in the puzzle, tend to increase
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        let mut depth = rng.between(100, 200) as i64;
+        for _ in 0..size {
+            writeln!(out, "{}", depth)?;
+            depth = (depth + rng.between(0, 22) as i64 - 10).clamp(0, 1_000_000);
+        }
+        return Ok(());
+    }
+}
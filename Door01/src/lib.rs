@@ -0,0 +1,290 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use aoc_common::parse;
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::top::{Rank, TopK};
+
+mod generate;
+pub mod window;
+
+use window::{Aggregate, SlidingWindow};
+
+// Instead of providing two separate solutions, we solve the more general problem of windows of size
+// n. Then, the solution for Part 1 is n=1 and part 2 is n=3.
+
+pub fn count_increases(depths : impl Iterator<Item = i32>, window_size : usize) -> u32 {
+    // With every new depth, one depth leaves the window and one enters it. Therefore, the sum over
+    // the window increases iff the new depth is larger than the one that left.
+    let mut window = SlidingWindow::new(window_size);
+    let mut results = 0;
+    for depth in depths {
+        if let Some(left) = window.push(depth) {
+            if depth > left {
+                results += 1;
+            }
+        }
+    }
+    return results;
+}
+
+/// A change of the aggregate between two consecutive windows
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Jump {
+    /// The 1-based number of the depth that entered the window
+    pub reading : usize,
+    pub change : f64
+}
+
+// Ranks jumps by their size, regardless of the direction
+#[derive(Default)]
+struct BySize;
+
+impl Rank<Jump> for BySize {
+    fn compare(&self, a : &Jump, b : &Jump) -> Ordering {
+        a.change.abs().total_cmp(&b.change.abs())
+    }
+}
+
+const JUMPS : usize = 5;
+
+/// How the aggregate over the windows develops
+#[derive(Debug, Default)]
+pub struct Trend {
+    pub increases : u32,
+    pub decreases : u32,
+    pub flat : u32,
+    /// The most consecutive increases
+    pub longest_run : u32,
+    /// The largest changes, largest first
+    pub jumps : Vec<Jump>
+}
+
+/// Follows the trend of the aggregate over windows of one size while the depths stream by
+pub struct Tracker {
+    window : SlidingWindow<i32>,
+    aggregate : Aggregate,
+    // Sum over the window, kept up to date so sums and means need not visit the whole window
+    sum : i64,
+    readings : usize,
+    previous : Option<f64>,
+    run : u32,
+    trend : Trend,
+    jumps : TopK<Jump, JUMPS, BySize>
+}
+
+impl Tracker {
+    pub fn new(window_size : usize, aggregate : Aggregate) -> Tracker {
+        Tracker{
+            window: SlidingWindow::new(window_size),
+            aggregate: aggregate,
+            sum: 0,
+            readings: 0,
+            previous: None,
+            run: 0,
+            trend: Trend::default(),
+            jumps: TopK::default()
+        }
+    }
+
+    pub fn push(&mut self, depth : i32) {
+        self.readings += 1;
+        self.sum += depth as i64;
+        if let Some(left) = self.window.push(depth) {
+            self.sum -= left as i64;
+        }
+        if !self.window.is_full() {
+            return;
+        }
+        let current = match self.aggregate {
+            Aggregate::Sum => self.sum as f64,
+            Aggregate::Mean => self.sum as f64 / self.window.size() as f64,
+            aggregate => aggregate.apply(&self.window)
+        };
+        if let Some(previous) = self.previous {
+            match current.total_cmp(&previous) {
+                Ordering::Greater => {
+                    self.trend.increases += 1;
+                    self.run += 1;
+                    self.trend.longest_run = std::cmp::max(self.trend.longest_run, self.run);
+                },
+                Ordering::Less => { self.trend.decreases += 1; self.run = 0; },
+                Ordering::Equal => { self.trend.flat += 1; self.run = 0; }
+            }
+            self.jumps.push(Jump{reading: self.readings, change: current - previous});
+        }
+        self.previous = Some(current);
+    }
+
+    pub fn finish(self) -> Trend {
+        let mut trend = self.trend;
+        trend.jumps = self.jumps.into_sorted_vec();
+        return trend;
+    }
+}
+
+pub fn trend(depths : impl Iterator<Item = i32>, window_size : usize, aggregate : Aggregate) -> Trend {
+    return trends(depths, &[window_size], aggregate).remove(0);
+}
+
+/// The trends for several window sizes, computed in a single pass over the depths
+pub fn trends(depths : impl Iterator<Item = i32>, window_sizes : &[usize], aggregate : Aggregate) -> Vec<Trend> {
+    let mut trackers : Vec<Tracker> = window_sizes.iter().map(|size| Tracker::new(*size, aggregate)).collect();
+    for depth in depths {
+        for tracker in &mut trackers {
+            tracker.push(depth);
+        }
+    }
+    return trackers.into_iter().map(Tracker::finish).collect();
+}
+
+impl fmt::Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Increases: {}", self.increases)?;
+        writeln!(f, "Decreases: {}", self.decreases)?;
+        writeln!(f, "Flat: {}", self.flat)?;
+        writeln!(f, "Longest increasing run: {}", self.longest_run)?;
+        write!(f, "Largest jumps:")?;
+        for jump in &self.jumps {
+            write!(f, "\n  {:+.2} at reading {}", jump.change, jump.reading)?;
+        }
+        return Ok(());
+    }
+}
+
+/// Streams the depths from a cursor, skipping lines that are not a number. Reading stops at the
+/// end of the input or at the first error, see [`Depths::finish`].
+pub struct Depths<'c, 'a> {
+    cursor : &'c mut parse::Cursor<'a>,
+    error : Option<parse::ParseError>
+}
+
+pub fn depths<'c, 'a>(cursor : &'c mut parse::Cursor<'a>) -> Depths<'c, 'a> {
+    Depths{cursor: cursor, error: None}
+}
+
+impl Depths<'_, '_> {
+    /// The error that stopped reading, if it was not the end of the input
+    pub fn finish(self) -> Result<(), parse::ParseError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(())
+        }
+    }
+}
+
+impl Iterator for Depths<'_, '_> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        if self.error.is_some() {
+            return None;
+        }
+        loop {
+            match parse::get_next_line(self.cursor) {
+                Ok(line) => {
+                    if let Ok(depth) = line.parse::<i32>() {
+                        return Some(depth);
+                    }
+                },
+                Err(parse::ParseError::Eof{..}) => { return None; },
+                Err(err) => {
+                    self.error = Some(err);
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+pub struct Door01;
+
+impl Solution for Door01 {
+    // The trends of single depths and of sums over three, so the depths are read without being
+    // kept in memory
+    type Input = (Trend, Trend);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<(Trend, Trend), parse::ParseError> {
+        let mut single = Tracker::new(1, Aggregate::Sum);
+        let mut sliding = Tracker::new(3, Aggregate::Sum);
+        let mut depths = depths(cursor);
+        for depth in &mut depths {
+            single.push(depth);
+            sliding.push(depth);
+        }
+        depths.finish()?;
+        return Ok((single.finish(), sliding.finish()));
+    }
+
+    fn part1((single, _) : &(Trend, Trend)) -> Answer<u32> {
+        Ok(single.increases)
+    }
+
+    fn part2((_, sliding) : &(Trend, Trend)) -> Answer<u32> {
+        Ok(sliding.increases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE : [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn puzzle_example() {
+        assert_eq!(count_increases(EXAMPLE.into_iter(), 1), 7);
+        assert_eq!(count_increases(EXAMPLE.into_iter(), 3), 5);
+    }
+
+    #[test]
+    fn trend_of_single_depths() {
+        let trend = trend(EXAMPLE.into_iter(), 1, Aggregate::Sum);
+        assert_eq!((trend.increases, trend.decreases, trend.flat), (7, 2, 0));
+        assert_eq!(trend.longest_run, 3);
+        let jumps : Vec<(usize, f64)> = trend.jumps.iter().map(|jump| (jump.reading, jump.change)).collect();
+        assert_eq!(jumps, [(7, 33.0), (8, 29.0), (5, -10.0), (9, -9.0), (3, 8.0)]);
+    }
+
+    #[test]
+    fn trend_of_sliding_sums() {
+        let trend = trend(EXAMPLE.into_iter(), 3, Aggregate::Sum);
+        assert_eq!((trend.increases, trend.decreases, trend.flat), (5, 1, 1));
+        assert_eq!(trend.longest_run, 4);
+    }
+
+    #[test]
+    fn window_larger_than_the_input() {
+        assert_eq!(count_increases(EXAMPLE.into_iter(), 11), 0);
+        let trend = trend(EXAMPLE.into_iter(), 11, Aggregate::Median);
+        assert_eq!((trend.increases, trend.decreases, trend.flat), (0, 0, 0));
+        assert!(trend.jumps.is_empty());
+    }
+
+    #[test]
+    fn trends_of_several_windows_in_one_pass() {
+        let trends = trends(EXAMPLE.into_iter(), &[1, 3, 11], Aggregate::Sum);
+        let increases : Vec<u32> = trends.iter().map(|trend| trend.increases).collect();
+        assert_eq!(increases, [7, 5, 0]);
+        assert_eq!(trends[1].jumps, trend(EXAMPLE.into_iter(), 3, Aggregate::Sum).jumps);
+    }
+
+    #[test]
+    fn means_and_maxima_of_sliding_windows() {
+        let by_mean = trend(EXAMPLE.into_iter(), 3, Aggregate::Mean);
+        assert_eq!((by_mean.increases, by_mean.decreases, by_mean.flat), (5, 1, 1));
+        assert_eq!(by_mean.jumps[0], Jump{reading: 8, change: 23.0});
+        let by_max = trend(EXAMPLE.into_iter(), 3, Aggregate::Max);
+        assert_eq!((by_max.increases, by_max.decreases, by_max.flat), (3, 0, 4));
+    }
+
+    #[test]
+    fn solution_streams_the_depths() {
+        let text = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        let input = Door01::parse(&mut parse::Cursor::new(text.split("\n"))).unwrap();
+        assert_eq!((Door01::part1(&input).unwrap(), Door01::part2(&input).unwrap()), (7, 5));
+        assert_eq!(input.1.jumps, trend(EXAMPLE.into_iter(), 3, Aggregate::Sum).jumps);
+    }
+}
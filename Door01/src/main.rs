@@ -1,49 +1,109 @@
 use std::env;
-use std::fs;
+use std::process;
 
-// Instead of providing two separate solutions, we solve the more general problem of windows of size
-// n. Then, the solution for Part 1 is n=1 and part 2 is n=3.
+use aoc_common::input;
+use aoc_common::parse::Cursor;
+use door01::window::Aggregate;
 
-fn main() {
-    // Read the input. We expect two parameters: the input file and the window size
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    let window_size = args[2].parse::<usize>().unwrap_or(1);
-
-    // Read the file from disk
-    let contents = fs::read_to_string(filename)
-        .expect("Something went terribly wrong while reading the file!");
-    let mut lines = contents.split("\n")
-                    .map(|line| line.parse::<i32>())
-                    .filter(|line| line.is_ok())
-                    .map(|line| line.unwrap());
-
-    // Initialize vec with first n numbers from the file
-    let mut window: Vec<i32> = vec![];
-    window.reserve(window_size);
-    for _ in 0..window_size {
-        let first = lines.next();
-        if first.is_none() {
-            println!("0");
-            return;
+static USAGE : &str = "Usage: door01 <file|-> [--window <n> | --windows <n,n,...>] [--aggregate <sum|mean|min|max|median>] [--report]";
+
+struct Options {
+    input : String,
+    windows : Vec<usize>,
+    // Whether several window sizes were requested, which are shown as a table
+    table : bool,
+    aggregate : Aggregate,
+    report : bool
+}
+
+fn parse_window(value : &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(size) if size > 0 => Ok(size),
+        _ => Err(format!("Invalid window size {}", value))
+    }
+}
+
+fn print_table(windows : &[usize], trends : &[door01::Trend]) {
+    println!("{:>8} {:>10} {:>10} {:>10} {:>12}", "window", "increases", "decreases", "flat", "longest run");
+    for (window, trend) in windows.iter().zip(trends) {
+        println!("{:>8} {:>10} {:>10} {:>10} {:>12}", window, trend.increases, trend.decreases, trend.flat, trend.longest_run);
+    }
+}
+
+fn parse_options(args : &[String]) -> Result<Options, String> {
+    let mut args = args.iter();
+    let input = args.next().ok_or("Missing input")?.clone();
+    let mut options = Options{input: input, windows: vec![1], table: false, aggregate: Aggregate::Sum, report: false};
+    while let Some(flag) = args.next() {
+        if flag == "--report" {
+            options.report = true;
+            continue;
+        }
+        let value = args.next().ok_or_else(|| format!("Missing value for {}", flag))?;
+        match flag.as_str() {
+            "--window" => {
+                options.windows = vec![parse_window(value)?];
+                options.table = false;
+            },
+            "--windows" => {
+                options.windows = value.split(',').map(parse_window).collect::<Result<_, _>>()?;
+                options.table = true;
+            },
+            "--aggregate" => { options.aggregate = value.parse()?; },
+            _ => { return Err(format!("Unknown option {}", flag)); }
         }
-        window.push(first.unwrap());
     }
+    return Ok(options);
+}
 
-    // Process the remaining lines
-    //
-    // Represent the window as a ringbuffer of constant size. With every new line, one value leaves
-    // the window and one value is added to the window. Therefore, the sum over the window increases
-    // iff the new number is larger than the removed one.
-    let mut count = 0;
-    let mut results = 0;
-    for line in lines {
-        let pos = count % window_size;
-        if line > window[pos] {
-            results += 1;
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_options(&args) {
+        Ok(options) => options,
+        Err(msg) => {
+            eprintln!("{}\n{}", msg, USAGE);
+            process::exit(2);
         }
-        window[pos] = line;
-        count += 1;
+    };
+
+    // Stream the depths, so the input never has to fit into memory
+    let reader = match input::open(&options.input) {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("{}: {}", options.input, err);
+            process::exit(1);
+        }
+    };
+    let mut cursor = Cursor::from_reader(reader);
+    let mut depths = door01::depths(&mut cursor);
+
+    if !options.table && !options.report && options.aggregate == Aggregate::Sum {
+        let count = door01::count_increases(&mut depths, options.windows[0]);
+        finish(depths, &options.input);
+        println!("{}", count);
+        return;
+    }
+    let trends = door01::trends(&mut depths, &options.windows, options.aggregate);
+    finish(depths, &options.input);
+    if options.table {
+        print_table(&options.windows, &trends);
+    }
+    if options.report {
+        for (window, trend) in options.windows.iter().zip(&trends) {
+            if options.table {
+                println!("\nWindow {}:", window);
+            }
+            println!("{}", trend);
+        }
+    } else if !options.table {
+        println!("{}", trends[0].increases);
+    }
+}
+
+// Stop with an error if the depths could not be read to the end
+fn finish(depths : door01::Depths, input : &str) {
+    if let Err(err) = depths.finish() {
+        eprintln!("{}: {}", input, err);
+        process::exit(1);
     }
-    println!("{}", results);
-}
\ No newline at end of file
+}
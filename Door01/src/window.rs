@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+/// The last `size` values of a stream, kept in a ring buffer
+#[derive(Clone, Debug)]
+pub struct SlidingWindow<T> {
+    values : Vec<T>,
+    size : usize,
+    // Position of the oldest value once the window is full
+    oldest : usize
+}
+
+impl<T : Copy> SlidingWindow<T> {
+    pub fn new(size : usize) -> SlidingWindow<T> {
+        assert!(size > 0, "Windows must hold at least one value");
+        return SlidingWindow{values: Vec::with_capacity(size), size: size, oldest: 0};
+    }
+
+    /// Adds `value` to the window. Once the window is full, returns the value that left it.
+    pub fn push(&mut self, value : T) -> Option<T> {
+        if self.values.len() < self.size {
+            self.values.push(value);
+            return None;
+        }
+        let left = std::mem::replace(&mut self.values[self.oldest], value);
+        self.oldest = (self.oldest + 1) % self.size;
+        return Some(left);
+    }
+
+    pub fn size(&self) -> usize { self.size }
+    pub fn len(&self) -> usize { self.values.len() }
+    pub fn is_empty(&self) -> bool { self.values.is_empty() }
+    pub fn is_full(&self) -> bool { self.values.len() == self.size }
+
+    /// The values from the oldest to the newest
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.values[self.oldest..].iter().chain(&self.values[..self.oldest]).copied()
+    }
+}
+
+/// How the values in a window are combined into a single one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregate { Sum, Mean, Min, Max, Median }
+
+impl Aggregate {
+    pub fn apply<T : Copy + Into<f64>>(&self, window : &SlidingWindow<T>) -> f64 {
+        let values = window.iter().map(|value| value.into());
+        match self {
+            Aggregate::Sum => values.sum(),
+            Aggregate::Mean => values.sum::<f64>() / window.len() as f64,
+            Aggregate::Min => values.fold(f64::INFINITY, f64::min),
+            Aggregate::Max => values.fold(f64::NEG_INFINITY, f64::max),
+            Aggregate::Median => {
+                let mut values : Vec<f64> = values.collect();
+                values.sort_by(f64::total_cmp);
+                let middle = values.len() / 2;
+                if values.len() % 2 == 1 { values[middle] } else { (values[middle-1] + values[middle]) / 2.0 }
+            }
+        }
+    }
+}
+
+impl FromStr for Aggregate {
+    type Err = String;
+
+    fn from_str(name : &str) -> Result<Aggregate, String> {
+        match name {
+            "sum" => Ok(Aggregate::Sum),
+            "mean" => Ok(Aggregate::Mean),
+            "min" => Ok(Aggregate::Min),
+            "max" => Ok(Aggregate::Max),
+            "median" => Ok(Aggregate::Median),
+            _ => Err(format!("Invalid aggregate {}, expected sum, mean, min, max or median", name))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(size : usize, values : &[u32]) -> SlidingWindow<u32> {
+        let mut window = SlidingWindow::new(size);
+        for value in values {
+            window.push(*value);
+        }
+        return window;
+    }
+
+    #[test]
+    fn push_returns_the_value_that_left() {
+        let mut window = SlidingWindow::new(2);
+        assert_eq!(window.push(1), None);
+        assert!(!window.is_full());
+        assert_eq!(window.push(2), None);
+        assert!(window.is_full());
+        assert_eq!(window.push(3), Some(1));
+        assert_eq!(window.push(4), Some(2));
+        assert_eq!(window.iter().collect::<Vec<_>>(), [3, 4]);
+        assert_eq!((window.len(), window.size()), (2, 2));
+    }
+
+    #[test]
+    fn iter_goes_from_oldest_to_newest() {
+        assert_eq!(window(3, &[1, 2, 3, 4, 5]).iter().collect::<Vec<_>>(), [3, 4, 5]);
+        assert_eq!(window(3, &[1, 2]).iter().collect::<Vec<_>>(), [1, 2]);
+        assert!(window(3, &[]).is_empty());
+    }
+
+    #[test]
+    fn aggregates() {
+        let values = window(4, &[9, 4, 1, 8, 2]);
+        assert_eq!(Aggregate::Sum.apply(&values), 15.0);
+        assert_eq!(Aggregate::Mean.apply(&values), 3.75);
+        assert_eq!(Aggregate::Min.apply(&values), 1.0);
+        assert_eq!(Aggregate::Max.apply(&values), 8.0);
+    }
+
+    #[test]
+    fn median_of_odd_and_even_windows() {
+        assert_eq!(Aggregate::Median.apply(&window(3, &[7, 1, 5])), 5.0);
+        assert_eq!(Aggregate::Median.apply(&window(4, &[7, 1, 5, 2])), 3.5);
+        // Not full yet, the median is over the values so far
+        assert_eq!(Aggregate::Median.apply(&window(5, &[8, 2])), 5.0);
+    }
+
+    #[test]
+    fn aggregate_names() {
+        assert_eq!("median".parse::<Aggregate>(), Ok(Aggregate::Median));
+        assert!("mode".parse::<Aggregate>().is_err());
+    }
+}
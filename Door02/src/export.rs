@@ -0,0 +1,178 @@
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use aoc_common::matrix::Matrix;
+use aoc_common::render::{self, Rgb};
+
+use crate::program::Statement;
+use crate::submarine::Submarine;
+
+/// How the trajectory is written, one step per line
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format { Csv, Jsonl }
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(name : &str) -> Result<Format, String> {
+        match name {
+            "csv" => Ok(Format::Csv),
+            "jsonl" => Ok(Format::Jsonl),
+            _ => Err(format!("Invalid format {}, expected csv or jsonl", name))
+        }
+    }
+}
+
+/// Writes what comes before the steps, i.e., the CSV header
+pub fn write_header(out : & mut dyn Write, format : Format) -> io::Result<()> {
+    match format {
+        Format::Csv => writeln!(out, "step,command,position,depth,aim"),
+        Format::Jsonl => Ok(())
+    }
+}
+
+/// Writes a single step as reported by [`Interpreter::observe`](crate::submarine::Interpreter::observe)
+pub fn write_step(out : & mut dyn Write, format : Format, step : usize, statement : &Statement, submarine : &Submarine) -> io::Result<()> {
+    // Commands consist of letters, digits, dashes and spaces, so they need no escaping
+    let Submarine{position, depth, aim, ..} = submarine;
+    match format {
+        Format::Csv => writeln!(out, "{},{},{},{},{}", step, statement.command, position, depth, aim),
+        Format::Jsonl => writeln!(out, "{{\"step\":{},\"command\":\"{}\",\"position\":{},\"depth\":{},\"aim\":{}}}",
+                                  step, statement.command, position, depth, aim)
+    }
+}
+
+/// The depth over the course of the steps, squeezed into a fixed number of columns. Every column
+/// holds the shallowest and the deepest depth of the steps it covers.
+pub struct Profile {
+    columns : Vec<(i64, i64)>,
+    width : usize,
+    // Steps per column, and steps in the last column so far
+    per_column : usize,
+    in_last : usize
+}
+
+impl Profile {
+    pub fn new(width : usize) -> Profile {
+        assert!(width >= 2, "Profiles need at least two columns");
+        return Profile{columns: Vec::with_capacity(width), width: width, per_column: 1, in_last: 0};
+    }
+
+    pub fn push(& mut self, depth : i64) {
+        if self.in_last == self.per_column || self.columns.is_empty() {
+            if self.columns.len() == self.width {
+                // Out of columns, so every column covers twice as many steps from now on
+                self.columns = self.columns.chunks(2)
+                    .map(|pair| pair.iter().fold((i64::MAX, i64::MIN), |(low, high), (min, max)| (low.min(*min), high.max(*max))))
+                    .collect();
+                self.per_column *= 2;
+                self.in_last = if self.width.is_multiple_of(2) { self.per_column } else { self.per_column / 2 };
+                return self.push(depth);
+            }
+            self.columns.push((depth, depth));
+            self.in_last = 0;
+        }
+        let last = self.columns.last_mut().unwrap();
+        *last = (last.0.min(depth), last.1.max(depth));
+        self.in_last += 1;
+    }
+
+    /// The profile as a matrix of `height` rows, with the surface of the shallowest depth at the
+    /// top. Entries are true where the submarine was.
+    pub fn to_matrix(&self, height : usize) -> Matrix<bool> {
+        let low = self.columns.iter().map(|(min, _)| *min).min().unwrap_or(0);
+        let high = self.columns.iter().map(|(_, max)| *max).max().unwrap_or(0);
+        let row = |depth : i64| {
+            // Depths may lie further apart than an i64 holds
+            if high == low { 0 } else { ((depth as i128 - low as i128) * (height as i128 - 1) / (high as i128 - low as i128)) as usize }
+        };
+        return Matrix::from_fn(self.columns.len(), height, |x, y| {
+            let (min, max) = self.columns[x];
+            row(min) <= y && y <= row(max)
+        });
+    }
+
+    pub fn to_text(&self, height : usize) -> String {
+        render::text(&self.to_matrix(height).map(|dot| if *dot { '#' } else { '.' }))
+    }
+
+    pub fn write_png(&self, height : usize, scale : usize, out : impl Write) -> io::Result<()> {
+        let image = self.to_matrix(height).map(|dot| if *dot { Rgb(255, 200, 0) } else { Rgb(0, 30, 80) });
+        return render::png(&image, scale, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::parse;
+
+    use crate::program;
+    use crate::submarine::{Aim, Interpreter, Surface};
+
+    // The trajectory of the puzzle example with aim
+    fn export(format : Format) -> String {
+        let text = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+        let program = program::parse_program(&mut parse::Cursor::new(text.split("\n"))).unwrap();
+        let mut out : Vec<u8> = vec!();
+        write_header(&mut out, format).unwrap();
+        {
+            let mut interpreter = Interpreter::new(&Aim, Surface::Error);
+            interpreter.observe(|step, statement, submarine| write_step(&mut out, format, step, statement, submarine).unwrap());
+            interpreter.run(&program, &mut Submarine::default()).unwrap();
+        }
+        return String::from_utf8(out).unwrap();
+    }
+
+    fn profile(width : usize, depths : &[i64]) -> Profile {
+        let mut profile = Profile::new(width);
+        for depth in depths {
+            profile.push(*depth);
+        }
+        return profile;
+    }
+
+    #[test]
+    fn csv_trajectory() {
+        assert_eq!(export(Format::Csv), "step,command,position,depth,aim\n\
+                                         1,forward 5,5,0,0\n\
+                                         2,down 5,5,0,5\n\
+                                         3,forward 8,13,40,5\n\
+                                         4,up 3,13,40,2\n\
+                                         5,down 8,13,40,10\n\
+                                         6,forward 2,15,60,10\n");
+    }
+
+    #[test]
+    fn jsonl_trajectory() {
+        let lines : Vec<String> = export(Format::Jsonl).lines().map(String::from).collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[2], r#"{"step":3,"command":"forward 8","position":13,"depth":40,"aim":5}"#);
+    }
+
+    #[test]
+    fn profile_within_its_width() {
+        let profile = profile(4, &[0, 40, 40, 60]);
+        assert_eq!(profile.to_text(4), "#...\n....\n.##.\n...#");
+    }
+
+    #[test]
+    fn profile_merges_columns_when_it_runs_out_of_them() {
+        let profile = profile(4, &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(profile.to_text(6), "#..\n#..\n.#.\n.#.\n..#\n..#");
+        let matrix = profile.to_matrix(3);
+        assert_eq!((matrix.get_width(), matrix.get_height()), (3, 3));
+    }
+
+    #[test]
+    fn flat_and_empty_profiles() {
+        assert_eq!(profile(3, &[7, 7]).to_text(2), "##\n..");
+        assert_eq!(profile(3, &[]).to_matrix(2).get_width(), 0);
+    }
+
+    #[test]
+    fn profile_of_extreme_depths() {
+        let profile = profile(3, &[i64::MIN, 0, i64::MAX]);
+        assert_eq!(profile.to_text(3), "#..\n.#.\n..#");
+    }
+}
@@ -0,0 +1,30 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door02;
+
+impl Generator for Door02 {
+    const SIZE : usize = 1000;
+
+    /// Writes `size` commands. The submarine never surfaces, neither with nor without aim.
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        // Up and down change depth and aim in the same way, so tracking either is enough
+        let mut aim = 0;
+        for _ in 0..size {
+            let command = rng.below(3);
+            let distance = rng.between(1, 9);
+            if command == 0 {
+                writeln!(out, "forward {}", distance)?;
+            } else if aim >= distance && (command == 1 || aim > 100) {
+                aim -= distance;
+                writeln!(out, "up {}", distance)?;
+            } else {
+                aim += distance;
+                writeln!(out, "down {}", distance)?;
+            }
+        }
+        return Ok(());
+    }
+}
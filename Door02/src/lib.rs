@@ -0,0 +1,68 @@
+use aoc_common::parse;
+use aoc_common::solution::{Answer, Solution};
+
+pub mod export;
+mod generate;
+pub mod program;
+pub mod submarine;
+
+use program::Program;
+use submarine::{Aim, Classic, Interpreter, MotionError, MotionModel, Submarine, Surface};
+
+/// Runs the program on a new submarine, which may not surface, and returns where it ends up
+pub fn navigate(program : &Program, model : &impl MotionModel) -> Result<Submarine, MotionError> {
+    let mut submarine = Submarine::default();
+    Interpreter::new(model, Surface::Error).run(program, &mut submarine)?;
+    return Ok(submarine);
+}
+
+// The product of depth and position, which may not overflow
+fn product(submarine : Submarine) -> Answer<i64> {
+    return submarine.depth.checked_mul(submarine.position)
+        .ok_or_else(|| "the product of depth and position overflows".into());
+}
+
+pub struct Door02;
+
+impl Solution for Door02 {
+    type Input = Program;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Program, parse::ParseError> {
+        program::parse_program(cursor)
+    }
+
+    fn part1(program : &Program) -> Answer<i64> {
+        product(navigate(program, &Classic)?)
+    }
+
+    fn part2(program : &Program) -> Answer<i64> {
+        product(navigate(program, &Aim)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(text : &str) -> Program {
+        program::parse_program(&mut parse::Cursor::new(text.split("\n"))).unwrap()
+    }
+
+    #[test]
+    fn answers_are_products() {
+        let program = program("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2");
+        assert_eq!(Door02::part1(&program).unwrap(), 150);
+        assert_eq!(Door02::part2(&program).unwrap(), 900);
+    }
+
+    #[test]
+    fn failures_are_errors() {
+        let surfacing = program("forward 1\nup 1");
+        assert_eq!(Door02::part1(&surfacing).unwrap_err().to_string(),
+                   "line 2: the submarine breaks the surface at position 1 and rises to depth -1");
+        let huge = program(&format!("down {0}\nforward {0}\nforward {0}\nforward 2", u32::MAX));
+        assert_eq!(Door02::part1(&huge).unwrap_err().to_string(), "the product of depth and position overflows");
+    }
+}
@@ -1,87 +1,166 @@
-use std::str::FromStr;
-use std::fmt;
 use std::env;
-use std::fs;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::process;
 
-enum Direction {
-    Forward,
-    Down,
-    Up
-}
+use aoc_common::input;
+use aoc_common::parse::Cursor;
 
-struct Command {
-    direction : Direction,
-    distance: u32
-}
+use door02::export::{self, Format, Profile};
+use door02::program::Program;
+use door02::submarine::{Aim, Classic, Interpreter, MotionError, MotionModel, Submarine, Surface};
+
+static USAGE : &str = "Usage: door02 <file|-> [--model <classic|aim>] [--surface <error|clamp|allow>] [--trace]
+              [--trajectory <file|->] [--format <csv|jsonl>] [--profile <file.png|->]";
 
-#[derive(Debug)]
-struct ParseCommandError {
-    cause : String
+// Size of depth profiles, and pixels per entry in PNGs of them
+static PROFILE_WIDTH : usize = 120;
+static PROFILE_HEIGHT : usize = 30;
+static PROFILE_SCALE : usize = 4;
+
+struct Options {
+    input : String,
+    use_aim : bool,
+    surface : Surface,
+    // Whether to list every point where the submarine broke the surface
+    trace : bool,
+    trajectory : Option<String>,
+    format : Format,
+    profile : Option<String>
 }
 
-impl fmt::Display for ParseCommandError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Error Parsing Command: {}", self.cause)
+fn parse_options(args : &[String]) -> Result<Options, String> {
+    let mut args = args.iter();
+    let input = args.next().ok_or("Missing input")?.clone();
+    let mut options = Options{
+        input: input,
+        use_aim: false,
+        surface: Surface::Error,
+        trace: false,
+        trajectory: None,
+        format: Format::Csv,
+        profile: None
+    };
+    while let Some(flag) = args.next() {
+        if flag == "--trace" {
+            options.trace = true;
+            continue;
+        }
+        let value = args.next().ok_or_else(|| format!("Missing value for {}", flag))?;
+        match flag.as_str() {
+            "--model" => {
+                options.use_aim = match value.as_str() {
+                    "classic" => false,
+                    "aim" => true,
+                    _ => { return Err(format!("Invalid model {}", value)); }
+                };
+            },
+            "--surface" => { options.surface = value.parse()?; },
+            "--trajectory" => { options.trajectory = Some(value.clone()); },
+            "--format" => { options.format = value.parse()?; },
+            "--profile" => { options.profile = Some(value.clone()); },
+            _ => { return Err(format!("Unknown option {}", flag)); }
+        }
     }
+    return Ok(options);
 }
 
-impl std::convert::From<std::num::ParseIntError> for ParseCommandError {
-    fn from(err : std::num::ParseIntError) -> ParseCommandError {
-        ParseCommandError{cause : err.to_string()}
+fn create(name : &str) -> io::Result<Box<dyn Write>> {
+    if name == "-" {
+        return Ok(Box::new(BufWriter::new(io::stdout().lock())));
     }
+    return Ok(Box::new(BufWriter::new(File::create(name)?)));
 }
 
-impl FromStr for Command {
-    type Err = ParseCommandError;
+fn fail(name : &str, err : impl std::fmt::Display) -> ! {
+    eprintln!("{}: {}", name, err);
+    process::exit(1);
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts :Vec<&str> = s.split(' ').collect();
+fn navigate(program : &Program, model : &impl MotionModel, options : &Options) -> Result<Submarine, MotionError> {
+    let mut trajectory = options.trajectory.as_ref()
+        .map(|name| create(name).and_then(|mut out| export::write_header(&mut out, options.format).map(|_| out))
+                                .unwrap_or_else(|err| fail(name, err)));
+    // The first error while writing the trajectory, which stops further writes
+    let mut written = Ok(());
+    let mut profile = options.profile.as_ref().map(|_| Profile::new(PROFILE_WIDTH));
 
-        let direction = match parts[0] {
-            "forward" => Ok(Direction::Forward),
-            "down" => Ok(Direction::Down),
-            "up" => Ok(Direction::Up),
-            other => Err(ParseCommandError{cause: other.to_string()})
-        }?;
-        let distance = parts[1].parse::<u32>()?;
+    let mut submarine = Submarine::default();
+    let result = {
+        let mut interpreter = Interpreter::new(model, options.surface);
+        if trajectory.is_some() || profile.is_some() {
+            interpreter.observe(|step, statement, submarine| {
+                if let Some(out) = &mut trajectory {
+                    if written.is_ok() {
+                        written = export::write_step(out, options.format, step, statement, submarine);
+                    }
+                }
+                if let Some(profile) = &mut profile {
+                    profile.push(submarine.depth);
+                }
+            });
+        }
+        let result = interpreter.run(program, &mut submarine);
+        if options.trace {
+            for breach in &interpreter.breaches {
+                println!("Broke the surface in line {} at position {}, depth {}", breach.line, breach.position, breach.depth);
+            }
+        }
+        result
+    };
 
-        Ok(Command{direction : direction, distance : distance})
+    if let (Some(name), Some(mut out)) = (&options.trajectory, trajectory) {
+        if let Err(err) = written.and_then(|_| out.flush()) {
+            fail(name, err);
+        }
+    }
+    if let (Some(name), Some(profile)) = (&options.profile, profile) {
+        let written = if name == "-" {
+            io::stdout().write_all(format!("{}\n", profile.to_text(PROFILE_HEIGHT)).as_bytes())
+        } else {
+            File::create(name).and_then(|file| profile.write_png(PROFILE_HEIGHT, PROFILE_SCALE, BufWriter::new(file)))
+        };
+        if let Err(err) = written {
+            fail(name, err);
+        }
     }
+    return result.map(|_| submarine);
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    let use_aim : bool = args[2].parse::<bool>().unwrap_or(false);
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_options(&args) {
+        Ok(options) => options,
+        Err(msg) => {
+            eprintln!("{}\n{}", msg, USAGE);
+            process::exit(2);
+        }
+    };
 
-    let contents = fs::read_to_string(filename)
-        .expect("Something went terribly wrong while reading the file!");
-    let lines = contents.split("\n")
-                 .map(|line| line.parse::<Command>())
-                 .filter(|line| line.is_ok())
-                 .map(|line| line.unwrap());
+    let reader = input::open(&options.input).unwrap_or_else(|err| fail(&options.input, err));
+    let mut cursor = Cursor::from_reader(reader);
+    let program = match door02::program::parse_program(&mut cursor) {
+        Ok(program) => program,
+        Err(err) => {
+            eprintln!("{}:{}", options.input, err);
+            process::exit(1);
+        }
+    };
 
-    let mut depth : u32 = 0;
-    let mut position : u32 = 0;
-    let mut aim : i32 = 0;
-    for Command{direction, distance} in lines {
-        if use_aim {
-            match direction {
-                Direction::Up => { aim -= distance as i32 }
-                Direction::Down => { aim += distance as i32 }
-                Direction::Forward => {
-                    position += distance;
-                    depth = (depth as i32 + (distance as i32*aim)) as u32;
-                }
-            }
-        } else {
-            match direction {
-                Direction::Forward => { position += distance }
-                Direction::Up => { depth -= distance }
-                Direction::Down => { depth += distance }
-            }
+    let result = if options.use_aim {
+        navigate(&program, &Aim, &options)
+    } else {
+        navigate(&program, &Classic, &options)
+    };
+    let Submarine{depth, position, ..} = match result {
+        Ok(submarine) => submarine,
+        Err(err) => {
+            eprintln!("{}:{}", options.input, err);
+            process::exit(1);
         }
+    };
+    match depth.checked_mul(position) {
+        Some(product) => println!("Depth: {}\nPosition: {}\nProduct: {}", depth, position, product),
+        None => println!("Depth: {}\nPosition: {}\nProduct: overflows", depth, position)
     }
-
-    println!("Depth: {}\nPosition: {}\nProduct: {}", depth, position, depth*position);
 }
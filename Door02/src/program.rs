@@ -0,0 +1,201 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_common::parse::{self, ParseError, Position};
+
+/// A single instruction of a submarine program
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    Forward(i64),
+    Back(i64),
+    Down(i64),
+    Up(i64),
+    /// Reverses the direction in which the submarine moves forward
+    Turn,
+    SetAim(i64),
+    /// Runs the statements the given number of times
+    Repeat(u32, Vec<Statement>)
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(distance) => write!(f, "forward {}", distance),
+            Command::Back(distance) => write!(f, "back {}", distance),
+            Command::Down(amount) => write!(f, "down {}", amount),
+            Command::Up(amount) => write!(f, "up {}", amount),
+            Command::Turn => write!(f, "turn"),
+            Command::SetAim(aim) => write!(f, "set-aim {}", aim),
+            Command::Repeat(count, _) => write!(f, "repeat {}", count)
+        }
+    }
+}
+
+/// A command together with the line it starts on
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement {
+    pub line : usize,
+    pub command : Command
+}
+
+pub type Program = Vec<Statement>;
+
+struct Token {
+    text : String,
+    at : Position
+}
+
+// Splits the input into words and braces. Comments run from '#' to the end of the line.
+fn tokenize(cursor : & mut parse::Cursor) -> Result<(Vec<Token>, Position), ParseError> {
+    let mut tokens = vec!();
+    loop {
+        let line = match parse::get_next_line(cursor) {
+            Ok(line) => String::from(line),
+            Err(ParseError::Eof{at}) => { return Ok((tokens, at)); },
+            Err(err) => { return Err(err); }
+        };
+        let start = cursor.position();
+        let code = line.split('#').next().unwrap();
+        let mut push = |text : &str, index : usize| {
+            tokens.push(Token{text: String::from(text), at: Position{line: start.line, column: start.column + index}});
+        };
+        let mut word = None;
+        for (index, c) in code.char_indices().chain([(code.len(), ' ')]) {
+            if c.is_whitespace() || c == '{' || c == '}' {
+                if let Some(begin) = word.take() {
+                    push(&code[begin..index], begin);
+                }
+                if !c.is_whitespace() {
+                    push(&code[index..index+1], index);
+                }
+            } else if word.is_none() {
+                word = Some(index);
+            }
+        }
+    }
+}
+
+struct Parser {
+    tokens : Vec<Token>,
+    next : usize,
+    // Where the input ended
+    end : Position
+}
+
+impl Parser {
+    fn advance(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.next)?;
+        self.next += 1;
+        return Some(token);
+    }
+
+    fn argument<A : FromStr>(&mut self, command : &str, at : Position) -> Result<A, ParseError>
+        where A::Err: Into<Box<dyn Error + Send + Sync>>
+    {
+        let end = self.end;
+        let Some(token) = self.advance() else {
+            return Err(ParseError::Invalid{at: end, message: format!("{} needs an argument", command)});
+        };
+        if token.at.line != at.line {
+            return Err(ParseError::Invalid{at: at, message: format!("{} needs an argument", command)});
+        }
+        return token.text.parse::<A>().map_err(|err| ParseError::Inner{at: token.at, source: err.into()});
+    }
+
+    // Parses statements up to the end of the input, or up to the '}' closing the repeat at `open`
+    fn block(&mut self, open : Option<Position>) -> Result<Vec<Statement>, ParseError> {
+        let mut statements = vec!();
+        loop {
+            let Some(token) = self.advance() else {
+                return match open {
+                    None => Ok(statements),
+                    Some(at) => Err(ParseError::Invalid{at: at, message: String::from("repeat is never closed")})
+                };
+            };
+            let (text, at) = (token.text.clone(), token.at);
+            let command = match text.as_str() {
+                "forward" => Command::Forward(self.argument::<u32>(&text, at)? as i64),
+                "back" => Command::Back(self.argument::<u32>(&text, at)? as i64),
+                "down" => Command::Down(self.argument::<u32>(&text, at)? as i64),
+                "up" => Command::Up(self.argument::<u32>(&text, at)? as i64),
+                "turn" => Command::Turn,
+                "set-aim" => Command::SetAim(self.argument::<i64>(&text, at)?),
+                "repeat" => {
+                    let count = self.argument::<u32>(&text, at)?;
+                    match self.advance() {
+                        Some(token) if token.text == "{" => (),
+                        _ => { return Err(ParseError::Invalid{at: at, message: String::from("repeat needs a block in braces")}); }
+                    }
+                    Command::Repeat(count, self.block(Some(at))?)
+                },
+                "}" if open.is_some() => { return Ok(statements); },
+                other => { return Err(ParseError::Invalid{at: at, message: format!("Unknown command {}", other)}); }
+            };
+            statements.push(Statement{line: at.line, command: command});
+        }
+    }
+}
+
+/// Reads a program of one command per line. Blocks of commands may be repeated with
+/// `repeat N { ... }`, which may span several lines.
+pub fn parse_program(cursor : & mut parse::Cursor) -> Result<Program, ParseError> {
+    let (tokens, end) = tokenize(cursor)?;
+    let mut parser = Parser{tokens: tokens, next: 0, end: end};
+    return parser.block(None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text : &str) -> Result<Program, ParseError> {
+        parse_program(&mut parse::Cursor::new(text.split("\n")))
+    }
+
+    fn commands(program : &Program) -> Vec<Command> {
+        program.iter().map(|statement| statement.command.clone()).collect()
+    }
+
+    fn message(text : &str) -> String {
+        match parse(text) {
+            Err(ParseError::Invalid{message, ..}) => message,
+            other => panic!("{:?} is not invalid", other.map(|program| commands(&program)))
+        }
+    }
+
+    #[test]
+    fn reads_one_command_per_line() {
+        let program = parse("forward 5\ndown 3\nup 1\nback 2\nturn\nset-aim -4").unwrap();
+        assert_eq!(commands(&program), [Command::Forward(5), Command::Down(3), Command::Up(1), Command::Back(2), Command::Turn, Command::SetAim(-4)]);
+        assert_eq!(program[1].line, program[0].line + 1);
+    }
+
+    #[test]
+    fn skips_comments_and_blank_lines() {
+        let program = parse("# a dive\n\nforward 5 # and on\n   \ndown 3#deeper\n#").unwrap();
+        assert_eq!(commands(&program), [Command::Forward(5), Command::Down(3)]);
+        assert_eq!(program[1].line, program[0].line + 2);
+    }
+
+    #[test]
+    fn nests_repeated_blocks() {
+        let program = parse("repeat 2 {\n  forward 1\n  repeat 3 { down 2 }\n}\nup 1").unwrap();
+        assert_eq!(program.len(), 2);
+        let Command::Repeat(2, outer) = &program[0].command else { panic!("{:?}", program[0]); };
+        assert_eq!(outer[0].command, Command::Forward(1));
+        assert_eq!(outer[1].command, Command::Repeat(3, vec![Statement{line: outer[1].line, command: Command::Down(2)}]));
+        assert_eq!(parse("repeat 0 {}").unwrap()[0].command, Command::Repeat(0, vec!()));
+    }
+
+    #[test]
+    fn rejects_broken_programs() {
+        assert_eq!(message("repeat 2 {\nforward 1"), "repeat is never closed");
+        assert_eq!(message("repeat 2 forward 1"), "repeat needs a block in braces");
+        assert_eq!(message("forward 1\nsideways 2"), "Unknown command sideways");
+        assert_eq!(message("forward\n5"), "forward needs an argument");
+        assert_eq!(message("down"), "down needs an argument");
+        assert_eq!(message("}"), "Unknown command }");
+        assert!(matches!(parse("forward -1"), Err(ParseError::Inner{..})));
+    }
+}
@@ -0,0 +1,265 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::program::{Command, Statement};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Submarine {
+    pub position : i64,
+    pub depth : i64,
+    pub aim : i64,
+    /// 1 while forward increases the position, -1 after turning around
+    pub heading : i64
+}
+
+impl Default for Submarine {
+    fn default() -> Submarine {
+        Submarine{position: 0, depth: 0, aim: 0, heading: 1}
+    }
+}
+
+/// How the submarine reacts to moving and diving. `set-aim` and `turn` act on the submarine
+/// directly. Both return None and leave the submarine as it was if a value would overflow.
+pub trait MotionModel {
+    /// Moves `distance` along the heading, backwards if it is negative
+    fn advance(&self, submarine : & mut Submarine, distance : i64) -> Option<()>;
+    /// Handles `down`, or `up` for negative amounts
+    fn dive(&self, submarine : & mut Submarine, amount : i64) -> Option<()>;
+}
+
+/// Diving changes the depth directly
+pub struct Classic;
+
+/// Diving changes the aim, and moving changes the depth by the aim for every step
+pub struct Aim;
+
+impl MotionModel for Classic {
+    fn advance(&self, submarine : & mut Submarine, distance : i64) -> Option<()> {
+        submarine.position = submarine.position.checked_add(distance.checked_mul(submarine.heading)?)?;
+        return Some(());
+    }
+
+    fn dive(&self, submarine : & mut Submarine, amount : i64) -> Option<()> {
+        submarine.depth = submarine.depth.checked_add(amount)?;
+        return Some(());
+    }
+}
+
+impl MotionModel for Aim {
+    fn advance(&self, submarine : & mut Submarine, distance : i64) -> Option<()> {
+        let position = submarine.position.checked_add(distance.checked_mul(submarine.heading)?)?;
+        let depth = submarine.depth.checked_add(distance.checked_mul(submarine.aim)?)?;
+        submarine.position = position;
+        submarine.depth = depth;
+        return Some(());
+    }
+
+    fn dive(&self, submarine : & mut Submarine, amount : i64) -> Option<()> {
+        submarine.aim = submarine.aim.checked_add(amount)?;
+        return Some(());
+    }
+}
+
+/// What happens when the submarine would rise above the surface, i.e., to a negative depth
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Surface {
+    /// Stop with [`MotionError::Surfaced`]
+    #[default]
+    Error,
+    /// Stay at depth 0
+    Clamp,
+    /// Continue at the negative depth
+    Allow
+}
+
+impl FromStr for Surface {
+    type Err = String;
+
+    fn from_str(name : &str) -> Result<Surface, String> {
+        match name {
+            "error" => Ok(Surface::Error),
+            "clamp" => Ok(Surface::Clamp),
+            "allow" => Ok(Surface::Allow),
+            _ => Err(format!("Invalid surface policy {}, expected error, clamp or allow", name))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MotionError {
+    Overflow { line : usize },
+    Surfaced { line : usize, position : i64, depth : i64 }
+}
+
+impl fmt::Display for MotionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MotionError::Overflow{line} =>
+                write!(f, "line {}: the position, depth or aim of the submarine overflows", line),
+            MotionError::Surfaced{line, position, depth} =>
+                write!(f, "line {}: the submarine breaks the surface at position {} and rises to depth {}", line, position, depth)
+        }
+    }
+}
+
+impl Error for MotionError {}
+
+/// A point where the submarine rose above the surface
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Breach {
+    pub line : usize,
+    pub position : i64,
+    pub depth : i64
+}
+
+/// Is called after every command that was executed with the number of the step, starting at 1,
+/// the command and the submarine after it
+pub type Observer<'m> = Box<dyn FnMut(usize, &Statement, &Submarine) + 'm>;
+
+/// Runs programs with a motion model and a surface policy
+pub struct Interpreter<'m, M> {
+    model : &'m M,
+    surface : Surface,
+    /// Every point where the submarine broke the surface so far, in order
+    pub breaches : Vec<Breach>,
+    steps : usize,
+    observer : Option<Observer<'m>>
+}
+
+impl<'m, M : MotionModel> Interpreter<'m, M> {
+    pub fn new(model : &'m M, surface : Surface) -> Interpreter<'m, M> {
+        Interpreter{model: model, surface: surface, breaches: vec!(), steps: 0, observer: None}
+    }
+
+    /// Calls `observer` after every step from now on. Repeat blocks are not steps themselves, only
+    /// the commands inside them.
+    pub fn observe(& mut self, observer : impl FnMut(usize, &Statement, &Submarine) + 'm) {
+        self.observer = Some(Box::new(observer));
+    }
+
+    /// Runs the statements on the submarine. On errors, the submarine stays where it was before
+    /// the offending command.
+    pub fn run(& mut self, statements : &[Statement], submarine : & mut Submarine) -> Result<(), MotionError> {
+        for statement in statements {
+            let line = statement.line;
+            let before = *submarine;
+            let moved = match &statement.command {
+                Command::Forward(distance) => self.model.advance(submarine, *distance),
+                Command::Back(distance) => self.model.advance(submarine, -distance),
+                Command::Down(amount) => self.model.dive(submarine, *amount),
+                Command::Up(amount) => self.model.dive(submarine, -amount),
+                Command::Turn => { submarine.heading = -submarine.heading; Some(()) },
+                Command::SetAim(aim) => { submarine.aim = *aim; Some(()) },
+                Command::Repeat(count, block) => {
+                    for _ in 0..*count {
+                        self.run(block, submarine)?;
+                    }
+                    continue;
+                }
+            };
+            if moved.is_none() {
+                return Err(MotionError::Overflow{line: line});
+            }
+            if submarine.depth < 0 {
+                self.surfaced(line, before, submarine)?;
+            }
+            self.steps += 1;
+            if let Some(observer) = &mut self.observer {
+                observer(self.steps, statement, submarine);
+            }
+        }
+        return Ok(());
+    }
+
+    fn surfaced(& mut self, line : usize, before : Submarine, submarine : & mut Submarine) -> Result<(), MotionError> {
+        let breach = Breach{line: line, position: submarine.position, depth: submarine.depth};
+        if before.depth >= 0 {
+            self.breaches.push(breach);
+        }
+        match self.surface {
+            Surface::Error => {
+                *submarine = before;
+                return Err(MotionError::Surfaced{line: line, position: breach.position, depth: breach.depth});
+            },
+            Surface::Clamp => { submarine.depth = 0; },
+            Surface::Allow => ()
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::program::{parse_program, Program};
+    use aoc_common::parse::Cursor;
+
+    fn program(text : &str) -> Program {
+        parse_program(&mut Cursor::new(text.split("\n"))).unwrap()
+    }
+
+    // Runs the program with the classic model and returns the outcome, the submarine and the
+    // depths of all breaches
+    fn run(text : &str, surface : Surface) -> (Result<(), MotionError>, Submarine, Vec<i64>) {
+        let mut interpreter = Interpreter::new(&Classic, surface);
+        let mut submarine = Submarine::default();
+        let result = interpreter.run(&program(text), &mut submarine);
+        let breaches = interpreter.breaches.iter().map(|breach| breach.depth).collect();
+        return (result, submarine, breaches);
+    }
+
+    static BOBBING : &str = "forward 2\ndown 1\nup 3\nup 1\ndown 5\nup 6";
+
+    #[test]
+    fn error_stops_before_the_surface() {
+        let (result, submarine, breaches) = run(BOBBING, Surface::Error);
+        let line = program(BOBBING)[2].line;
+        assert_eq!(result, Err(MotionError::Surfaced{line: line, position: 2, depth: -2}));
+        assert_eq!((submarine.position, submarine.depth), (2, 1));
+        assert_eq!(breaches, [-2]);
+    }
+
+    #[test]
+    fn clamp_stays_at_the_surface() {
+        let (result, submarine, breaches) = run(BOBBING, Surface::Clamp);
+        assert_eq!(result, Ok(()));
+        assert_eq!((submarine.position, submarine.depth), (2, 0));
+        assert_eq!(breaches, [-2, -1, -1]);
+    }
+
+    #[test]
+    fn allow_continues_above_the_surface() {
+        let (result, submarine, breaches) = run(BOBBING, Surface::Allow);
+        assert_eq!(result, Ok(()));
+        assert_eq!(submarine.depth, -4);
+        // Rising further while above the surface is no new breach
+        assert_eq!(breaches, [-2, -4]);
+    }
+
+    #[test]
+    fn aim_surfaces_while_moving_forward() {
+        let mut interpreter = Interpreter::new(&Aim, Surface::Clamp);
+        let mut submarine = Submarine::default();
+        interpreter.run(&program("up 2\nforward 3\ndown 4\nforward 1"), &mut submarine).unwrap();
+        assert_eq!((submarine.position, submarine.depth, submarine.aim), (4, 2, 2));
+        assert_eq!(interpreter.breaches, [Breach{line: interpreter.breaches[0].line, position: 3, depth: -6}]);
+    }
+
+    #[test]
+    fn overflows_are_errors() {
+        let mut interpreter = Interpreter::new(&Aim, Surface::Error);
+        let mut submarine = Submarine::default();
+        let result = interpreter.run(&program(&format!("set-aim {}\nforward 1\nforward 2", i64::MAX)), &mut submarine);
+        assert_eq!(result, Err(MotionError::Overflow{line: 3}));
+        assert_eq!((submarine.position, submarine.depth), (1, i64::MAX));
+    }
+
+    #[test]
+    fn surface_policies_by_name() {
+        assert_eq!("clamp".parse(), Ok(Surface::Clamp));
+        assert_eq!("allow".parse(), Ok(Surface::Allow));
+        assert_eq!("error".parse(), Ok(Surface::Error));
+        assert!("float".parse::<Surface>().is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::{co2_rating, oxy_rating, Door03};
+
+// Whether filtering the numbers by `pred` leaves exactly one of them, like the ratings require
+fn has_rating(numbers : &[usize], width : usize, pred : fn(usize, usize) -> bool) -> bool {
+    let mut remaining = numbers.to_vec();
+    for digit in (0..width).rev() {
+        if remaining.len() <= 1 {
+            break;
+        }
+        let ones = remaining.iter().filter(|number| (*number >> digit) & 1 == 1).count();
+        let keep = pred(ones, remaining.len() - ones);
+        remaining.retain(|number| ((number >> digit) & 1 == 1) == keep);
+    }
+    return remaining.len() == 1;
+}
+
+impl Generator for Door03 {
+    const SIZE : usize = 1000;
+
+    /// Writes `size` distinct bit strings for which both ratings exist. Sets of strings which the
+    /// ratings filter down to nothing are thrown away.
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        let mut width = 5;
+        while (1 << width) < 4 * size {
+            width += 1;
+        }
+        // The set only rules out duplicates, the order of the strings is kept in the vector
+        let mut seen = HashSet::new();
+        let mut numbers = vec!();
+        loop {
+            seen.clear();
+            numbers.clear();
+            while numbers.len() < size {
+                let number = rng.below(1 << width);
+                if seen.insert(number) {
+                    numbers.push(number);
+                }
+            }
+            if has_rating(&numbers, width, oxy_rating) && has_rating(&numbers, width, co2_rating) {
+                break;
+            }
+        }
+        for number in numbers {
+            writeln!(out, "{:0width$b}", number, width = width)?;
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,313 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Index;
+use std::str::FromStr;
+
+use aoc_common::parse;
+use aoc_common::solution::{Answer, Solution};
+
+mod generate;
+pub mod trie;
+
+use trie::{Criterion, Tie, Trie};
+
+/// A string of bits of arbitrary width, packed into words. Bit `i` of the string is bit `i % 64`
+/// of word `i / 64`, and the unused bits of the last word are always 0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bits {
+    words : Vec<u64>,
+    len : usize
+}
+
+static WORD : usize = 64;
+
+#[derive(Debug)]
+pub struct BitstringParseError {}
+
+/// A bit string whose value does not fit the requested integer type
+#[derive(Debug, PartialEq, Eq)]
+pub struct BitsOverflow {
+    pub len : usize
+}
+
+impl fmt::Display for BitsOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the value of a {} bit string overflows", self.len)
+    }
+}
+
+impl Error for BitsOverflow {}
+
+impl FromStr for Bits {
+    type Err = BitstringParseError;
+
+    fn from_str (bs : &str) -> Result<Bits,BitstringParseError> {
+        let mut bits = Bits::zeros(bs.len());
+        for (index, ch) in bs.bytes().enumerate() {
+            match ch {
+                b'0' => (),
+                b'1' => bits.set(index, true),
+                _ => { return Err(BitstringParseError{}); }
+            }
+        }
+        return Ok(bits);
+    }
+}
+
+impl fmt::Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for index in 0..self.len {
+            write!(f, "{}", if self.get(index) { '1' } else { '0' })?;
+        }
+        return Ok(());
+    }
+}
+
+/// The value of the bits, the first one being the most significant
+impl TryFrom<&Bits> for u128 {
+    type Error = BitsOverflow;
+
+    fn try_from(bits : &Bits) -> Result<u128, BitsOverflow> {
+        let mut result : u128 = 0;
+        for index in 0..bits.len {
+            if result.leading_zeros() == 0 {
+                return Err(BitsOverflow{len: bits.len});
+            }
+            result = result << 1 | bits.get(index) as u128;
+        }
+        return Ok(result);
+    }
+}
+
+impl TryFrom<&Bits> for u64 {
+    type Error = BitsOverflow;
+
+    fn try_from(bits : &Bits) -> Result<u64, BitsOverflow> {
+        u64::try_from(u128::try_from(bits)?).map_err(|_| BitsOverflow{len: bits.len})
+    }
+}
+
+impl Index<usize> for Bits {
+    type Output = bool;
+
+    fn index(&self, index : usize) -> &Self::Output {
+        if self.get(index) { &true } else { &false }
+    }
+}
+
+impl Bits {
+    pub fn zeros(len : usize) -> Bits {
+        Bits{words: vec![0; len.div_ceil(WORD)], len: len}
+    }
+
+    pub fn get(&self, index : usize) -> bool {
+        assert!(index < self.len);
+        return self.words[index / WORD] >> (index % WORD) & 1 == 1;
+    }
+
+    pub fn set(&mut self, index : usize, value : bool) {
+        assert!(index < self.len);
+        let mask = 1 << (index % WORD);
+        if value {
+            self.words[index / WORD] |= mask;
+        } else {
+            self.words[index / WORD] &= !mask;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    pub fn words(&self) -> &[u64] {
+        return &self.words;
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|word| word.count_ones()).sum()
+    }
+}
+
+/// Counts the ones in every column of the bit strings added to it
+#[derive(Default)]
+pub struct Diagnostic {
+    total : u64,
+    width : usize,
+    // The counts as bit-sliced binary numbers: bit `j` of the count of column `i` is bit `i` of
+    // `planes[j]`, with columns packed as in `Bits`. Adding a bit string is then a binary increment
+    // on whole words, which carries into the next plane only where both bits are set.
+    planes : Vec<Vec<u64>>
+}
+
+impl Diagnostic {
+    pub fn new() -> Diagnostic {
+        Diagnostic{total: 0, width: 0, planes: vec!()}
+    }
+
+    pub fn add(&mut self, bits: &Bits) {
+        // All planes cover all columns, so counts can read every column from every plane
+        if bits.len() > self.width {
+            self.width = bits.len();
+            let words = self.width.div_ceil(WORD);
+            for plane in &mut self.planes {
+                plane.resize(words, 0);
+            }
+        }
+        let mut carry = bits.words().to_vec();
+        for plane in 0.. {
+            if carry.iter().all(|word| *word == 0) {
+                break;
+            }
+            if plane == self.planes.len() {
+                self.planes.push(vec![0; self.width.div_ceil(WORD)]);
+            }
+            let plane = &mut self.planes[plane];
+            for (word, carry) in plane.iter_mut().zip(carry.iter_mut()) {
+                let sum = *word ^ *carry;
+                *carry &= *word;
+                *word = sum;
+            }
+        }
+        self.total += 1;
+    }
+
+    /// The number of ones in every column
+    pub fn counts(&self) -> Vec<u64> {
+        (0..self.width)
+            .map(|column| self.planes.iter().enumerate()
+                .map(|(j, plane)| (plane[column / WORD] >> (column % WORD) & 1) << j)
+                .sum())
+            .collect()
+    }
+
+    // The bits which are set in the columns whose count satisfies `pred`
+    fn select(&self, pred : impl Fn(u64) -> bool) -> Bits {
+        let mut bits = Bits::zeros(self.width);
+        for (column, count) in self.counts().into_iter().enumerate() {
+            bits.set(column, pred(count));
+        }
+        return bits;
+    }
+
+    pub fn gamma(&self) -> Bits {
+        let threshold = self.total / 2;
+        return self.select(|count| count > threshold);
+    }
+
+    pub fn epsilon(&self) -> Bits {
+        let threshold = self.total / 2;
+        return self.select(|count| count <= threshold);
+    }
+}
+
+pub fn oxy_rating(ones : usize, zeros : usize) -> bool {
+    return ones >= zeros
+}
+
+pub fn co2_rating(ones : usize, zeros : usize) -> bool {
+    return ones < zeros
+}
+
+/// The criterion of the oxygen generator rating
+pub static OXYGEN : Criterion = Criterion{keep: oxy_rating, tie: Tie::Criterion};
+
+/// The criterion of the CO2 scrubber rating
+pub static CO2 : Criterion = Criterion{keep: co2_rating, tie: Tie::Criterion};
+
+/// The product of the oxygen generator and the CO2 scrubber rating
+pub fn life_support_rating(bits : &[Bits]) -> Result<u128, Box<dyn Error>> {
+    let mut trie = Trie::new();
+    bits.iter().for_each(|entry| trie.insert(entry));
+    return Ok(product(&trie.rating(&OXYGEN)?, &trie.rating(&CO2)?)?);
+}
+
+pub fn power_consumption(bits : &[Bits]) -> Result<u128, BitsOverflow> {
+    let mut diag : Diagnostic = Diagnostic::new();
+    bits.iter().for_each(|entry| diag.add(entry));
+    return product(&diag.epsilon(), &diag.gamma());
+}
+
+// The product of the values of two bit strings
+fn product(lhs : &Bits, rhs : &Bits) -> Result<u128, BitsOverflow> {
+    u128::try_from(lhs)?.checked_mul(u128::try_from(rhs)?).ok_or(BitsOverflow{len: lhs.len() + rhs.len()})
+}
+
+pub struct Door03;
+
+impl Solution for Door03 {
+    type Input = Vec<Bits>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Vec<Bits>, parse::ParseError> {
+        let mut bits = vec!();
+        loop {
+            let line = match parse::get_next_line(cursor) {
+                Ok(line) => line,
+                Err(parse::ParseError::Eof{..}) => { break; },
+                Err(err) => { return Err(err); }
+            };
+            if let Ok(entry) = line.parse::<Bits>() {
+                if !entry.is_empty() {
+                    bits.push(entry);
+                }
+            }
+        }
+        return Ok(bits);
+    }
+
+    fn part1(bits : &Vec<Bits>) -> Answer<u128> {
+        Ok(power_consumption(bits)?)
+    }
+
+    fn part2(bits : &Vec<Bits>) -> Answer<u128> {
+        life_support_rating(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic(rows : &[&str]) -> Diagnostic {
+        let mut diag = Diagnostic::new();
+        for row in rows {
+            diag.add(&row.parse::<Bits>().unwrap());
+        }
+        return diag;
+    }
+
+    #[test]
+    fn counts_rows_of_mixed_widths() {
+        let wide = format!("{}1{}", "0".repeat(80), "0".repeat(19));
+        let diag = diagnostic(&["1", "1", &wide]);
+        let mut expected = vec![0; 100];
+        expected[0] = 2;
+        expected[80] = 1;
+        assert_eq!(diag.counts(), expected);
+    }
+
+    #[test]
+    fn counts_more_than_64_columns() {
+        let rows = ["1".repeat(130), format!("0{}", "1".repeat(129)), format!("{}0", "1".repeat(129))];
+        let diag = diagnostic(&rows.iter().map(String::as_str).collect::<Vec<_>>());
+        let counts = diag.counts();
+        assert_eq!(counts.len(), 130);
+        assert_eq!((counts[0], counts[64], counts[128], counts[129]), (2, 3, 3, 2));
+        assert_eq!(diag.gamma().to_string(), "1".repeat(130));
+        assert_eq!(diag.epsilon().count_ones(), 0);
+    }
+
+    #[test]
+    fn converts_with_overflow_errors() {
+        let wide : Bits = "1".repeat(65).parse().unwrap();
+        assert_eq!(u64::try_from(&wide), Err(BitsOverflow{len: 65}));
+        assert_eq!(u128::try_from(&wide), Ok((1 << 65) - 1));
+        let padded : Bits = format!("{}101", "0".repeat(200)).parse().unwrap();
+        assert_eq!(u64::try_from(&padded), Ok(5));
+    }
+}
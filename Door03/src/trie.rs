@@ -0,0 +1,198 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::Bits;
+
+/// Which strings a rating keeps when as many have a one as a zero at the current digit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tie {
+    /// Leave it to the criterion
+    Criterion,
+    Ones,
+    Zeros,
+    /// Fail with [`RatingError::Tie`]
+    Error
+}
+
+/// How a rating picks the strings to keep at every digit: `keep` gets the number of remaining
+/// strings with a one and with a zero there, and returns whether to keep the ones
+#[derive(Clone, Copy, Debug)]
+pub struct Criterion {
+    pub keep : fn(usize, usize) -> bool,
+    pub tie : Tie
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RatingError {
+    /// There were no strings to begin with
+    Empty,
+    /// The criterion chose the bit no remaining string has
+    NoneLeft { digit : usize },
+    Tie { digit : usize },
+    /// Several strings are left when one of them ends, i.e., there are duplicates
+    Ambiguous { remaining : usize }
+}
+
+impl fmt::Display for RatingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RatingError::Empty => write!(f, "there are no bit strings to rate"),
+            RatingError::NoneLeft{digit} => write!(f, "no bit strings are left after digit {}", digit),
+            RatingError::Tie{digit} => write!(f, "as many bit strings have a one as a zero at digit {}", digit),
+            RatingError::Ambiguous{remaining} => write!(f, "{} bit strings are left when one of them ends", remaining)
+        }
+    }
+}
+
+impl Error for RatingError {}
+
+// Children are indices into the nodes, 0 means there is none since the root is no child
+#[derive(Clone, Default)]
+struct Node {
+    children : [usize; 2],
+    // The number of strings that pass this node, and that end in it
+    count : usize,
+    ends : usize
+}
+
+/// Bit strings stored by their common prefixes. Every node knows how many strings lie below it,
+/// so a rating only walks down once.
+pub struct Trie {
+    nodes : Vec<Node>
+}
+
+impl Default for Trie {
+    fn default() -> Trie { Trie::new() }
+}
+
+impl Trie {
+    pub fn new() -> Trie {
+        Trie{nodes: vec![Node::default()]}
+    }
+
+    pub fn insert(&mut self, bits : &Bits) {
+        let mut node = 0;
+        self.nodes[node].count += 1;
+        for digit in 0..bits.len() {
+            let bit = bits.get(digit) as usize;
+            if self.nodes[node].children[bit] == 0 {
+                self.nodes[node].children[bit] = self.nodes.len();
+                self.nodes.push(Node::default());
+            }
+            node = self.nodes[node].children[bit];
+            self.nodes[node].count += 1;
+        }
+        self.nodes[node].ends += 1;
+    }
+
+    /// The number of strings
+    pub fn len(&self) -> usize { self.nodes[0].count }
+    pub fn is_empty(&self) -> bool { self.nodes[0].count == 0 }
+
+    // The number of strings below the child of `node` for `bit`
+    fn count(&self, node : usize, bit : usize) -> usize {
+        match self.nodes[node].children[bit] {
+            0 => 0,
+            child => self.nodes[child].count
+        }
+    }
+
+    /// Keeps the strings chosen by `criterion` digit by digit until one is left, and returns it
+    pub fn rating(&self, criterion : &Criterion) -> Result<Bits, RatingError> {
+        if self.is_empty() {
+            return Err(RatingError::Empty);
+        }
+        let mut path = vec!();
+        let mut node = 0;
+        loop {
+            let Node{count, ends, ..} = self.nodes[node];
+            if ends > 0 {
+                if count > 1 {
+                    return Err(RatingError::Ambiguous{remaining: count});
+                }
+                break;
+            }
+            let digit = path.len();
+            let (ones, zeros) = (self.count(node, 1), self.count(node, 0));
+            let keep_ones = if ones != zeros {
+                (criterion.keep)(ones, zeros)
+            } else {
+                match criterion.tie {
+                    Tie::Criterion => (criterion.keep)(ones, zeros),
+                    Tie::Ones => true,
+                    Tie::Zeros => false,
+                    Tie::Error => { return Err(RatingError::Tie{digit: digit}); }
+                }
+            };
+            // A single string is its own rating, whatever the criterion says
+            let bit = if count == 1 { (ones == 1) as usize } else { keep_ones as usize };
+            if self.count(node, bit) == 0 {
+                return Err(RatingError::NoneLeft{digit: digit});
+            }
+            path.push(bit == 1);
+            node = self.nodes[node].children[bit];
+        }
+        let mut bits = Bits::zeros(path.len());
+        for (digit, bit) in path.into_iter().enumerate() {
+            bits.set(digit, bit);
+        }
+        return Ok(bits);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{co2_rating, oxy_rating, CO2, OXYGEN};
+
+    fn trie(rows : &[&str]) -> Trie {
+        let mut trie = Trie::new();
+        rows.iter().for_each(|row| trie.insert(&row.parse().unwrap()));
+        return trie;
+    }
+
+    fn rating(rows : &[&str], criterion : &Criterion) -> Result<String, RatingError> {
+        trie(rows).rating(criterion).map(|bits| bits.to_string())
+    }
+
+    static EXAMPLE : [&str; 12] = ["00100", "11110", "10110", "10111", "10101", "01111",
+                                   "00111", "11100", "10000", "11001", "00010", "01010"];
+
+    #[test]
+    fn rates_the_example() {
+        assert_eq!(trie(&EXAMPLE).len(), 12);
+        assert_eq!(rating(&EXAMPLE, &OXYGEN), Ok(String::from("10111")));
+        assert_eq!(rating(&EXAMPLE, &CO2), Ok(String::from("01010")));
+    }
+
+    #[test]
+    fn ties() {
+        let error = Criterion{keep: oxy_rating, tie: Tie::Error};
+        assert_eq!(rating(&["10", "01"], &error), Err(RatingError::Tie{digit: 0}));
+        assert_eq!(rating(&["110", "101", "000"], &error), Err(RatingError::Tie{digit: 1}));
+        let fewest = Criterion{keep: co2_rating, tie: Tie::Error};
+        assert_eq!(rating(&["110", "100", "000"], &fewest), Ok(String::from("000")));
+
+        let ones = Criterion{keep: co2_rating, tie: Tie::Ones};
+        let zeros = Criterion{keep: oxy_rating, tie: Tie::Zeros};
+        assert_eq!(rating(&["10", "01"], &ones), Ok(String::from("10")));
+        assert_eq!(rating(&["10", "01"], &zeros), Ok(String::from("01")));
+        assert_eq!(rating(&["10", "01"], &CO2), Ok(String::from("01")));
+    }
+
+    #[test]
+    fn duplicates_and_prefixes_are_ambiguous() {
+        assert_eq!(rating(&["101", "101", "100"], &OXYGEN), Err(RatingError::Ambiguous{remaining: 2}));
+        assert_eq!(rating(&["10", "101"], &OXYGEN), Err(RatingError::Ambiguous{remaining: 2}));
+        assert_eq!(rating(&["0", "0", "1", "1", "1"], &CO2), Err(RatingError::Ambiguous{remaining: 2}));
+    }
+
+    #[test]
+    fn nothing_to_rate() {
+        assert_eq!(rating(&[], &OXYGEN), Err(RatingError::Empty));
+        let zeros = Criterion{keep: |_, _| false, tie: Tie::Criterion};
+        assert_eq!(rating(&["11", "10"], &zeros), Err(RatingError::NoneLeft{digit: 0}));
+        // A single string is kept whatever the criterion says
+        assert_eq!(rating(&["11"], &zeros), Ok(String::from("11")));
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Clone,Debug)]
 pub struct Bingo {
     numbers : Vec<Vec<u32>>,
     called : Vec<Vec<bool>>
@@ -0,0 +1,61 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door04;
+
+static NUMBERS : usize = 100;
+static CARD : usize = 5;
+
+fn card(rng : & mut Rng) -> Vec<usize> {
+    let mut numbers : Vec<usize> = (0..NUMBERS).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(CARD*CARD);
+    return numbers;
+}
+
+// The number of draws until the card completes, given when each number is drawn
+fn completion(card : &[usize], drawn_at : &[usize]) -> usize {
+    let row = (0..CARD).map(|row| (0..CARD).map(|col| drawn_at[card[row*CARD + col]]).max().unwrap());
+    let col = (0..CARD).map(|col| (0..CARD).map(|row| drawn_at[card[row*CARD + col]]).max().unwrap());
+    return row.chain(col).min().unwrap();
+}
+
+impl Generator for Door04 {
+    const SIZE : usize = 100;
+
+    /// Writes the drawn numbers followed by `size` bingo cards. Every number gets drawn, so every
+    /// card completes eventually, and exactly one card completes last.
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        let mut numbers : Vec<usize> = (0..NUMBERS).collect();
+        rng.shuffle(&mut numbers);
+        let mut drawn_at = vec![0; NUMBERS];
+        for (index, number) in numbers.iter().enumerate() {
+            drawn_at[*number] = index;
+        }
+
+        // Replace cards that tie for the last place until there is only one
+        let mut cards : Vec<Vec<usize>> = (0..size).map(|_| card(rng)).collect();
+        loop {
+            let completions : Vec<usize> = cards.iter().map(|card| completion(card, &drawn_at)).collect();
+            let last = completions.iter().max().copied().unwrap_or(0);
+            let tied : Vec<usize> = (0..cards.len()).filter(|index| completions[*index] == last).collect();
+            if tied.len() <= 1 {
+                break;
+            }
+            cards[tied[0]] = card(rng);
+        }
+
+        let drawn : Vec<String> = numbers.iter().map(usize::to_string).collect();
+        writeln!(out, "{}", drawn.join(","))?;
+        for card in cards {
+            writeln!(out)?;
+            for row in card.chunks(CARD) {
+                let row : Vec<String> = row.iter().map(|number| format!("{:>2}", number)).collect();
+                writeln!(out, "{}", row.join(" "))?;
+            }
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_common::parse;
+use aoc_common::solution::{Answer, Solution};
+
+pub mod bingo;
+mod generate;
+
+fn parse_bingo(cursor : & mut parse::Cursor) -> Result<bingo::Bingo, parse::ParseError> {
+    let first_line : Vec<u32> = parse::list(cursor, ' ')?;
+    let size = first_line.len();
+    let mut other_lines : Vec<Vec<u32>> = parse::n(cursor, size-1, |cur| parse::list(cur, ' '))?;
+    parse::empty(cursor)?;
+    let mut bingo = vec!(first_line);
+    bingo.append(& mut other_lines);
+    return bingo::Bingo::new(bingo).map_err(|msg| cursor.error(msg));
+}
+
+pub struct Game {
+    pub numbers : Vec<u32>,
+    pub bingos : Vec<bingo::Bingo>
+}
+
+/// Calls the numbers until every bingo is complete. Returns the scores of the first and the last
+/// bingo to complete, or None if some bingo never completes.
+pub fn play(game : &Game) -> Option<(u32, u32)> {
+    let mut first : Option<u32> = None;
+    let mut bingos = game.bingos.clone();
+    let mut last : bool = bingos.len() == 1;
+    for &number in &game.numbers {
+        let mut remaining_bingos = vec!();
+        for mut bingo in bingos {
+            bingo.call(number);
+            if bingo.is_complete() {
+                if first.is_none() {
+                    first = Some(bingo.score() * number);
+                }
+                if last {
+                    return Some((first.unwrap(), bingo.score() * number));
+                }
+            } else {
+                remaining_bingos.push(bingo);
+            }
+        }
+        if remaining_bingos.len() == 1 {
+            last = true;
+        }
+        bingos = remaining_bingos;
+    }
+    return None;
+}
+
+pub struct Door04;
+
+impl Solution for Door04 {
+    type Input = Game;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Game, parse::ParseError> {
+        let numbers : Vec<u32> = parse::list(cursor, ',')?;
+        parse::empty(cursor)?;
+        let bingos = parse::all(cursor, parse_bingo)?;
+        return Ok(Game{numbers: numbers, bingos: bingos});
+    }
+
+    fn part1(game : &Game) -> Answer<u32> {
+        let (first, _) = play(game).ok_or("Some bingo never completes")?;
+        Ok(first)
+    }
+
+    fn part2(game : &Game) -> Answer<u32> {
+        let (_, last) = play(game).ok_or("Some bingo never completes")?;
+        Ok(last)
+    }
+}
@@ -0,0 +1,37 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door05;
+
+static AREA : i64 = 1000;
+static DIRECTIONS : [(i64, i64); 8] = [(1, 0), (0, 1), (-1, 0), (0, -1), (1, 1), (1, -1), (-1, -1), (-1, 1)];
+
+// How many steps a line starting at `start` can take in direction `step` before leaving the area
+fn room(start : i64, step : i64) -> i64 {
+    match step {
+        1 => AREA - 1 - start,
+        -1 => start,
+        _ => i64::MAX
+    }
+}
+
+impl Generator for Door05 {
+    const SIZE : usize = 500;
+
+    /// Writes `size` vent lines, both straight and diagonal ones
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        for i in 0..size {
+            let x = rng.below(AREA as usize) as i64;
+            let y = rng.below(AREA as usize) as i64;
+            let (dx, dy) = *rng.choose(&DIRECTIONS);
+            let length = (rng.below(100) as i64).min(room(x, dx)).min(room(y, dy));
+            if i > 0 {
+                writeln!(out)?;
+            }
+            write!(out, "{},{} -> {},{}", x, y, x + dx*length, y + dy*length)?;
+        }
+        return Ok(());
+    }
+}
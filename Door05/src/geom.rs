@@ -1,3 +1,6 @@
+use aoc_common::grid::Grid;
+use aoc_common::matrix::Coordinates;
+
 #[derive(Clone,Copy,Debug)]
 pub struct Point {
     x : u32,
@@ -44,9 +47,9 @@ impl Line {
      */
     pub fn length(&self) -> usize {
         if !self.is_vertical() {
-            return (self.start.x + self.end.x - 2*std::cmp::min(self.start.x, self.end.x)) as usize +1;
+            return self.start.x.abs_diff(self.end.x) as usize +1;
         } else {
-            return (self.start.y + self.end.y - 2*std::cmp::min(self.start.y, self.end.y)) as usize +1;
+            return self.start.y.abs_diff(self.end.y) as usize +1;
         }
     }
 
@@ -54,7 +57,7 @@ impl Line {
 
     fn get_coordinates(start: u32, end: u32, length: usize, straight: bool) -> Vec<u32> {
         if straight {
-            return std::iter::repeat(start).take(length).collect();
+            return std::iter::repeat_n(start, length).collect();
         }
         let range = std::cmp::min(start, end) ..= std::cmp::max(start, end);
         if start > end {
@@ -81,20 +84,35 @@ impl Line {
     }
 }
 
+impl From<Point> for Coordinates {
+    fn from(point : Point) -> Coordinates { Coordinates((point.x as usize, point.y as usize)) }
+}
+
+impl From<Point> for Coordinates<i64> {
+    fn from(point : Point) -> Coordinates<i64> { Coordinates((point.x as i64, point.y as i64)) }
+}
+
+/// Counts how many lines cover each point of a dense or sparse grid
 #[derive(Debug)]
-pub struct Area {
-    counts : Vec<Vec<u32>>
+pub struct Area<G> {
+    counts : G,
+    // Points covered by more than one line so far
+    crossings : u32
 }
 
-impl Area {
-    pub fn new(bound : &Point) -> Area {
-        let x = (bound.x + 1).try_into().unwrap();
-        let y = (bound.y + 1).try_into().unwrap();
-        Area{counts: vec![vec![0; y]; x]}
+impl<G : Grid<Value = u32>> Area<G> where G::Position : From<Point> {
+    /// The grid must cover every point that will be drawn and hold 0 everywhere
+    pub fn new(counts : G) -> Area<G> {
+        Area{counts: counts, crossings: 0}
     }
 
     pub fn draw_point(&mut self, point: &Point) {
-        self.counts[point.x as usize][point.y as usize] += 1;
+        let position = G::Position::from(*point);
+        let count = self.counts.get(position).unwrap() + 1;
+        self.counts.set(position, count);
+        if count == 2 {
+            self.crossings += 1;
+        }
     }
 
     pub fn draw_line(&mut self, line: &Line) {
@@ -104,11 +122,6 @@ impl Area {
     }
 
     pub fn count_crossing(&self) -> u32 {
-        self.counts.iter()
-                   .map(|vec| vec.iter()
-                                 .filter(|val| **val > 1)
-                                 .count()
-                    ).map(|val| val as u32)
-                    .sum()
+        self.crossings
     }
 }
@@ -0,0 +1,90 @@
+use aoc_common::grid::Grid;
+use aoc_common::matrix::Matrix;
+use aoc_common::parse;
+use aoc_common::sparse::SparseGrid;
+use aoc_common::solution::{Answer, Solution};
+
+mod generate;
+pub mod geom;
+
+fn parse_point(cursor : & mut parse::Cursor) -> Result<geom::Point, parse::ParseError>  {
+    let coordinates = parse::list(cursor, ',')?;
+    if coordinates.len() != 2 {
+        return Err(cursor.error("Invalid number of coordiantes in point"));
+    }
+    return Ok(geom::Point::from_coordinates(coordinates[0], coordinates[1]));
+}
+
+fn parse_line(cursor : & mut parse::Cursor) -> Result<geom::Line, parse::ParseError>  {
+    let points = parse::n(&mut parse::split(cursor, " -> ")?, 2, parse_point)?;
+    return Ok(geom::Line::from_points(points[0], points[1]));
+}
+
+fn draw<G : Grid<Value = u32>>(lines : &[&geom::Line], mut map : geom::Area<G>) -> u32
+    where G::Position : From<geom::Point>
+{
+    for line in lines {
+        map.draw_line(line);
+    }
+    return map.count_crossing();
+}
+
+/// Draws the straight lines and, if requested, the diagonal ones. Returns the number of points
+/// covered by more than one line.
+pub fn count_overlaps(lines : &[geom::Line], diagonals : bool) -> u32 {
+    let lines : Vec<&geom::Line> = lines.iter().filter(|line| diagonals || line.is_straight()).collect();
+    let bound = lines.iter().fold(geom::Point::zero(), |lhs, rhs| lhs.skyline(&rhs.get_bound()));
+    let points : usize = lines.iter().map(|line| line.length()).sum();
+    // A dense grid is faster, unless a few far away points make it much larger than the lines
+    let (width, height) = (bound.get_x() as usize + 1, bound.get_y() as usize + 1);
+    if width.checked_mul(height).is_some_and(|area| area <= points.saturating_mul(4)) {
+        return draw(&lines, geom::Area::new(Matrix::new(width, height)));
+    }
+    return draw(&lines, geom::Area::new(SparseGrid::new(0)));
+}
+
+pub struct Door05;
+
+impl Solution for Door05 {
+    type Input = Vec<geom::Line>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Vec<geom::Line>, parse::ParseError> {
+        let mut lines = vec!();
+        while !cursor.at_end() {
+            lines.push(parse_line(cursor)?);
+        }
+        return Ok(lines);
+    }
+
+    fn part1(lines : &Vec<geom::Line>) -> Answer<u32> {
+        Ok(count_overlaps(lines, false))
+    }
+
+    fn part2(lines : &Vec<geom::Line>) -> Answer<u32> {
+        Ok(count_overlaps(lines, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text : &str) -> Vec<geom::Line> {
+        Door05::parse(&mut parse::Cursor::new(text.split("\n"))).unwrap()
+    }
+
+    #[test]
+    fn example() {
+        let lines = parse("0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n2,2 -> 2,1\n7,0 -> 7,4\n6,4 -> 2,0\n0,9 -> 2,9\n3,4 -> 1,4\n0,0 -> 8,8\n5,5 -> 8,2\n");
+        assert_eq!(count_overlaps(&lines, false), 5);
+        assert_eq!(count_overlaps(&lines, true), 12);
+    }
+
+    #[test]
+    fn lines_at_the_edge_of_u32() {
+        let lines = parse("0,0 -> 0,2\n0,1 -> 0,3\n4294967295,4294967295 -> 4294967295,4294967294\n4294967295,4294967295 -> 4294967294,4294967295");
+        assert_eq!(count_overlaps(&lines, false), 3);
+    }
+}
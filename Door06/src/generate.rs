@@ -0,0 +1,21 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door06;
+
+impl Generator for Door06 {
+    const SIZE : usize = 300;
+
+    /// Writes the ages of `size` lanternfish
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        for i in 0..size {
+            if i > 0 {
+                write!(out, ",")?;
+            }
+            write!(out, "{}", rng.between(1, 5))?;
+        }
+        return writeln!(out);
+    }
+}
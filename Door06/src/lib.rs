@@ -0,0 +1,37 @@
+use aoc_common::parse;
+use aoc_common::solution::{Answer, Solution};
+
+mod generate;
+pub mod population;
+
+/// Returns the size of the population after the given number of generations
+pub fn simulate(ages : &[usize], generations : usize) -> u64 {
+    let mut population = population::Population::empty(7, 2);
+    for &age in ages {
+        population.put(age);
+    }
+    for _ in 0..generations {
+        population.cycle();
+    }
+    return population.count();
+}
+
+pub struct Door06;
+
+impl Solution for Door06 {
+    type Input = Vec<usize>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Vec<usize>, parse::ParseError> {
+        parse::list(cursor, ',')
+    }
+
+    fn part1(ages : &Vec<usize>) -> Answer<u64> {
+        Ok(simulate(ages, 80))
+    }
+
+    fn part2(ages : &Vec<usize>) -> Answer<u64> {
+        Ok(simulate(ages, 256))
+    }
+}
@@ -0,0 +1,22 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door07;
+
+impl Generator for Door07 {
+    const SIZE : usize = 1000;
+
+    /// Writes the positions of `size` crabs. Like in the puzzle, most of them are close to zero.
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        for i in 0..size {
+            if i > 0 {
+                write!(out, ",")?;
+            }
+            let spread = rng.between(1, 2000);
+            write!(out, "{}", rng.below(spread))?;
+        }
+        return writeln!(out);
+    }
+}
@@ -0,0 +1,91 @@
+use aoc_common::parse;
+use aoc_common::solution::{Answer, Solution};
+
+mod generate;
+
+/// Moves the crabs with constant fuel cost per step. Returns the position and the fuel used, or
+/// `None` without crabs.
+pub fn align_linear(crabs : &[usize]) -> Option<(usize, usize)> {
+    let width = crabs.iter().max()?;
+    let mut area = vec![0; *width+1];
+    for crab in crabs {
+        area[*crab] += 1;
+    }
+    let mut fuel = 0;
+    let mut left = 0;
+    let mut right = *width;
+    while left != right {
+        if area[left] < area[right] {
+            let old = area[left];
+            left += 1;
+            area[left] += old;
+            fuel += old;
+        } else {
+            let old = area[right];
+            right -= 1;
+            area[right] += old;
+            fuel += old;
+        }
+    }
+    return Some((left, fuel));
+}
+
+/// Moves the crabs with increasing fuel cost per step. Returns the position and the fuel used, or
+/// `None` without crabs.
+pub fn align_triangular(crabs : &[usize]) -> Option<(usize, usize)> {
+    let width = crabs.iter().max()?;
+    let mut minfuel = usize::MAX;
+    let mut minposition = 0; 
+    for position in 0..=*width {
+        let mut fuel = 0;
+        for crab in crabs {
+            let distance = position + *crab - 2*std::cmp::min(position, *crab);
+            fuel += (distance + distance*distance)/2;
+        }
+        if fuel < minfuel {
+            minfuel = fuel;
+            minposition = position;
+        }
+    }
+    return Some((minposition, minfuel));
+}
+
+pub struct Door07;
+
+impl Solution for Door07 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Vec<usize>, parse::ParseError> {
+        parse::list(cursor, ',')
+    }
+
+    fn part1(crabs : &Vec<usize>) -> Answer<usize> {
+        Ok(align_linear(crabs).ok_or("There are no crabs")?.1)
+    }
+
+    fn part2(crabs : &Vec<usize>) -> Answer<usize> {
+        Ok(align_triangular(crabs).ok_or("There are no crabs")?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_example() {
+        let crabs = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        assert_eq!(align_linear(&crabs), Some((2, 37)));
+        assert_eq!(align_triangular(&crabs), Some((5, 168)));
+    }
+
+    #[test]
+    fn no_crabs() {
+        let crabs = Door07::parse(&mut parse::Cursor::new([""].into_iter())).unwrap();
+        assert_eq!(align_linear(&crabs), None);
+        assert_eq!(Door07::part1(&crabs).unwrap_err().to_string(), "There are no crabs");
+        assert_eq!(Door07::part2(&crabs).unwrap_err().to_string(), "There are no crabs");
+    }
+}
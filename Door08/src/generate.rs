@@ -0,0 +1,42 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door08;
+
+// The segments of the digits 0 to 9 in the correct wiring
+static DIGITS : [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+// The digit's segments under `wiring`, in random order
+fn scramble(rng : & mut Rng, wiring : &[char], digit : usize) -> String {
+    let mut segments : Vec<char> = DIGITS[digit].chars()
+        .map(|segment| wiring[segment as usize - 'a' as usize])
+        .collect();
+    rng.shuffle(&mut segments);
+    return segments.into_iter().collect();
+}
+
+impl Generator for Door08 {
+    const SIZE : usize = 200;
+
+    /// Writes `size` displays, each with its own random wiring
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        let mut wiring : Vec<char> = "abcdefg".chars().collect();
+        let mut digits : Vec<usize> = (0..10).collect();
+        for i in 0..size {
+            rng.shuffle(&mut wiring);
+            rng.shuffle(&mut digits);
+            let input : Vec<String> = digits.iter().map(|digit| scramble(rng, &wiring, *digit)).collect();
+            let output : Vec<String> = (0..4).map(|_| {
+                let digit = rng.below(10);
+                scramble(rng, &wiring, digit)
+            }).collect();
+            if i > 0 {
+                writeln!(out)?;
+            }
+            write!(out, "{} | {}", input.join(" "), output.join(" "))?;
+        }
+        return Ok(());
+    }
+}
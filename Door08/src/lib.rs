@@ -0,0 +1,186 @@
+use aoc_common::parse;
+use aoc_common::solution::{Answer, Solution};
+
+mod generate;
+
+enum Segment {
+    Top = 0,
+    TopLeft = 1,
+    TopRight = 2,
+    Middle = 3,
+    BottomLeft = 4,
+    BottomRight = 5,
+    Bottom = 6
+}
+
+#[derive(Clone,Debug)]
+pub struct Signal {
+    segments : [bool;7]
+}
+
+impl std::str::FromStr for Signal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut ret = Signal{segments: [false; 7]};
+        for character in s.chars() {
+            let index = match character {
+                'a' => Ok(Segment::Top),
+                'b' => Ok(Segment::TopLeft),
+                'c' => Ok(Segment::TopRight),
+                'd' => Ok(Segment::Middle),
+                'e' => Ok(Segment::BottomLeft),
+                'f' => Ok(Segment::BottomRight),
+                'g' => Ok(Segment::Bottom),
+                _ => Err("Unknown signal")
+            }? as usize;
+            ret.segments[index] = true;
+        }
+        return Ok(ret);
+    }
+}
+
+impl Signal {
+    fn count(&self) -> u32{
+        self.segments.iter().map(|entry| *entry as u32).sum()
+    }
+
+    fn embeds(&self, other: &Signal) -> bool {
+        for position in 0..7 {
+            if other.segments[position] && !self.segments[position] {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    fn equals(&self, other: &Signal) -> bool {
+        self.segments.iter()
+                     .zip(other.segments.iter())
+                     .all(|(lhs, rhs)| lhs==rhs)
+    }
+
+    fn is_one(&self) -> bool { self.count() == 2 }
+    fn is_seven(&self) -> bool { self.count() == 3 }
+    fn is_four(&self) -> bool { self.count() == 4 }
+    fn is_eight(&self) -> bool { self.count() == 7 }
+    fn is_three(&self, one: &Signal) -> bool { self.count() == 5 && self.embeds(one) }
+    fn is_zero(&self, one: &Signal) -> bool { self.count() == 6 && self.embeds(one) }
+    fn is_nine(&self, three: &Signal) -> bool { self.count() == 6 && self.embeds(three) }
+    fn is_six(&self, one: &Signal) -> bool { self.count() == 6 && !self.embeds(one) }
+    fn is_five(&self, six: &Signal) -> bool { self.count() == 5 && six.embeds(self) }
+
+    fn find_signal(list: &mut Vec<Signal>, pred: impl Fn(&Signal) -> bool) -> Option<Signal> {
+        for index in 0..list.len() {
+            if pred(&list[index]) {
+                return Some(list.remove(index));
+            }
+        }
+        return None;
+    }
+}
+
+pub type Row = (Vec<Signal>,Vec<Signal>);
+
+fn parse_row(cursor : & mut parse::Cursor)
+ -> Result<Row, parse::ParseError> {
+    let mut line = parse::split(cursor, " | ")?;
+    let input :Vec<Signal> = parse::list(&mut line, ' ')?;
+    let output :Vec<Signal> = parse::list(&mut line, ' ')?;
+    return Ok((input, output))
+}
+
+/// Decodes every row. Returns how many output digits are 1, 4, 7 or 8 and the sum over the
+/// output values, or which row does not decode.
+pub fn decode(data : &[Row]) -> Result<(u32, usize), String> {
+    let mut part1 = 0;
+    let mut part2 : usize = 0;
+    for (row, (input, output)) in data.iter().enumerate() {
+        let undecodable = || format!("Row {} does not show the ten digits", row + 1);
+        let mut input = input.clone();
+        let one   = Signal::find_signal(&mut input, Signal::is_one).ok_or_else(undecodable)?;
+        let seven = Signal::find_signal(&mut input, Signal::is_seven).ok_or_else(undecodable)?;
+        let four  = Signal::find_signal(&mut input, Signal::is_four).ok_or_else(undecodable)?;
+        let eight = Signal::find_signal(&mut input, Signal::is_eight).ok_or_else(undecodable)?;
+        let three = Signal::find_signal(&mut input, |signal| signal.is_three(&one)).ok_or_else(undecodable)?;
+        let nine  = Signal::find_signal(&mut input, |signal| signal.is_nine(&three)).ok_or_else(undecodable)?;
+        let zero  = Signal::find_signal(&mut input, |signal| signal.is_zero(&one)).ok_or_else(undecodable)?;
+        let six   = Signal::find_signal(&mut input, |signal| signal.is_six(&one)).ok_or_else(undecodable)?;
+        let five  = Signal::find_signal(&mut input, |signal| signal.is_five(&six)).ok_or_else(undecodable)?;
+        if input.len() != 1 {
+            return Err(undecodable());
+        }
+        let two = input.remove(0);
+        let decoder = [zero,one,two,three,four,five,six,seven,eight,nine];
+
+        let overflow = || format!("The output value of row {} overflows", row + 1);
+        let mut value : usize = 0;
+        'outer: for segment in output {
+            for (digit, candidate) in decoder.iter().enumerate() {
+                if segment.equals(candidate) {
+                    value = value.checked_mul(10).and_then(|value| value.checked_add(digit)).ok_or_else(overflow)?;
+                    if digit == 1 || digit == 4 || digit == 7 || digit == 8 {
+                        part1 += 1;
+                    }
+                    continue 'outer;
+                }
+            }
+            return Err(format!("Row {} shows an output that is no digit", row + 1));
+        }
+        part2 = part2.checked_add(value).ok_or("The sum of the output values overflows")?;
+    }
+    return Ok((part1, part2));
+}
+
+pub struct Door08;
+
+impl Solution for Door08 {
+    type Input = Vec<Row>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Vec<Row>, parse::ParseError> {
+        parse::all(cursor, parse_row)
+    }
+
+    fn part1(data : &Vec<Row>) -> Answer<u32> {
+        Ok(decode(data)?.0)
+    }
+
+    fn part2(data : &Vec<Row>) -> Answer<usize> {
+        Ok(decode(data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(text : &str) -> Vec<Row> {
+        Door08::parse(&mut parse::Cursor::new(text.split("\n"))).unwrap()
+    }
+
+    const EXAMPLE : &str = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+    #[test]
+    fn puzzle_example() {
+        assert_eq!(decode(&rows(EXAMPLE)), Ok((0, 5353)));
+        assert_eq!(decode(&rows(&EXAMPLE.replace("| cdfeb", "| ab"))), Ok((1, 1353)));
+    }
+
+    #[test]
+    fn rows_without_the_ten_digits() {
+        let missing_one = EXAMPLE.replace(" ab |", " |");
+        let data = rows(&format!("{}\n{}", EXAMPLE, missing_one));
+        assert_eq!(decode(&data), Err(String::from("Row 2 does not show the ten digits")));
+        assert_eq!(Door08::part1(&data).unwrap_err().to_string(), "Row 2 does not show the ten digits");
+        let twice = rows(&EXAMPLE.replace("cefabd", "ab"));
+        assert_eq!(decode(&twice), Err(String::from("Row 1 does not show the ten digits")));
+    }
+
+    #[test]
+    fn outputs_which_are_no_digit() {
+        let data = rows(&EXAMPLE.replace("| cdfeb", "| abc"));
+        assert_eq!(Door08::part2(&data).unwrap_err().to_string(), "Row 1 shows an output that is no digit");
+    }
+}
@@ -0,0 +1,26 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door09;
+
+impl Generator for Door09 {
+    const SIZE : usize = 100;
+
+    /// Writes a square heightmap with side length `size`
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        let mut row = String::with_capacity(size);
+        for y in 0..size {
+            row.clear();
+            for _ in 0..size {
+                row.push(char::from_digit(rng.below(10) as u32, 10).unwrap());
+            }
+            if y > 0 {
+                writeln!(out)?;
+            }
+            write!(out, "{}", row)?;
+        }
+        return Ok(());
+    }
+}
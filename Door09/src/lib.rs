@@ -0,0 +1,72 @@
+use aoc_common::matrix::{ComponentInfo, Coordinates, Matrix, Stencil};
+use aoc_common::parse;
+use aoc_common::render::{self, Rgb};
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::top::TopK;
+
+mod generate;
+
+fn parse_matrix(cursor : & mut parse::Cursor) -> Result<Matrix<u32>, parse::ParseError> {
+    parse::matrix(cursor, |cursor| parse::characters(cursor, |c| {
+        match c.to_digit(10) {
+            Some(n) => Ok(n),
+            None => Err("Digit was no digit")
+        }
+    }))
+}
+
+pub fn find_low_points(heightmap: &Matrix<u32>) -> Vec<Coordinates> {
+    let mut low_points = vec!();
+    'fields: for coords in heightmap.coords_iter() {
+        let val = heightmap[coords];
+        for &neighbor in heightmap.get_all(&mut heightmap.neighbor_coords(coords)) {
+            if neighbor <= val {
+                continue 'fields
+            }
+        }
+        low_points.push(coords);
+    }
+    return low_points;
+}
+
+fn risk_level(height: u32) -> u32 { height +1 }
+
+pub fn total_risk_level(heightmap: &Matrix<u32>) -> u32 {
+    find_low_points(heightmap).iter().map(|coords| risk_level(heightmap[*coords])).sum::<u32>()
+}
+
+/// All basins, i.e., the areas bordered by heights of 9
+pub fn basins(heightmap: &Matrix<u32>) -> Vec<ComponentInfo> {
+    heightmap.label_components(|height| *height != 9, &Stencil::von_neumann(1)).1
+}
+
+/// The three biggest basins, biggest first
+pub fn biggest_basins(heightmap: &Matrix<u32>) -> Vec<ComponentInfo> {
+    let mut biggest_basins = TopK::<_, 3, _>::by_key(|basin : &ComponentInfo| basin.size);
+    biggest_basins.extend(basins(heightmap));
+    return biggest_basins.into_sorted_vec();
+}
+
+pub struct Door09;
+
+impl Solution for Door09 {
+    type Input = Matrix<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Matrix<u32>, parse::ParseError> {
+        parse_matrix(cursor)
+    }
+
+    fn part1(heightmap : &Matrix<u32>) -> Answer<u32> {
+        Ok(total_risk_level(heightmap))
+    }
+
+    fn part2(heightmap : &Matrix<u32>) -> Answer<u32> {
+        Ok(biggest_basins(heightmap).iter().map(|basin| basin.size as u32).product::<u32>())
+    }
+
+    fn render(heightmap : &Matrix<u32>) -> Option<Matrix<Rgb>> {
+        Some(heightmap.map(|level| render::heat(*level as f64 / 9.0)))
+    }
+}
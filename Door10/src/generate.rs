@@ -0,0 +1,54 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door10;
+
+static BRACKETS : [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+// Most brackets left open at once. Completing 27 of them scores at most 5^27-1, which still fits
+// into the u64 completion score, 28 would not.
+static MAX_OPEN : usize = 27;
+
+impl Generator for Door10 {
+    const SIZE : usize = 100;
+
+    /// Writes `size` lines of navigation subsystem, about half of them corrupted and the others
+    /// incomplete
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        let mut line = String::new();
+        let mut stack = vec!();
+        for _ in 0..size {
+            line.clear();
+            stack.clear();
+            let length = rng.between(90, 110);
+            let corrupt = if rng.one_in(2) { Some(rng.below(length)) } else { None };
+            for i in 0..length {
+                if corrupt == Some(i) {
+                    // Close with anything but the expected bracket
+                    let expected = stack.pop();
+                    let (_, close) = loop {
+                        let candidate = *rng.choose(&BRACKETS);
+                        if Some(candidate.1) != expected {
+                            break candidate;
+                        }
+                    };
+                    line.push(close);
+                } else if stack.is_empty() || (stack.len() < MAX_OPEN && rng.below(5) < 3) {
+                    let (open, close) = *rng.choose(&BRACKETS);
+                    line.push(open);
+                    stack.push(close);
+                } else {
+                    line.push(stack.pop().unwrap());
+                }
+            }
+            if corrupt.is_none() && stack.is_empty() {
+                let (open, _) = *rng.choose(&BRACKETS);
+                line.push(open);
+            }
+            writeln!(out, "{}", line)?;
+        }
+        return Ok(());
+    }
+}
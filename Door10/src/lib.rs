@@ -0,0 +1,190 @@
+use aoc_common::parse;
+use aoc_common::solution::{Answer, Solution};
+
+mod generate;
+
+fn find_corresponding_closing_bracket(ch: char) -> Option<char> {
+    let brackets = [('(',')'), ('[',']'), ('{','}'), ('<','>')];
+    for (open, close) in brackets {
+        if ch == open {
+            return Some(close);
+        }
+    }
+    None
+}
+
+// Scores of the closing brackets, other characters have none
+fn error_score(ch: char) -> Option<u32> {
+    match ch {
+        ')' => Some(3),
+        ']' => Some(57),
+        '}' => Some(1197),
+        '>' => Some(25137),
+        _ => None
+    }
+}
+
+fn complete_score(ch: char) -> Option<u64> {
+    match ch {
+        ')' => Some(1),
+        ']' => Some(2),
+        '}' => Some(3),
+        '>' => Some(4),
+        _ => None
+    }
+}
+
+fn unknown(ch: char) -> String {
+    format!("Unknown character {:?}", ch)
+}
+
+/// The outcome of checking a single line
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The line closes a chunk with the wrong bracket, the first of which is given
+    Corrupted(char),
+    /// The line is missing closing brackets, completing it scores the given points
+    Incomplete(u64)
+}
+
+/// Checks a line of brackets, other characters are an error
+pub fn check_line(line : &str) -> Result<Status, String> {
+    let mut stack = vec![];
+    for ch in line.chars() {
+        match find_corresponding_closing_bracket(ch) {
+            Some(close) => {
+                stack.push(close);
+            },
+            None => {
+                if error_score(ch).is_none() {
+                    return Err(unknown(ch));
+                }
+                let next =stack.pop();
+                if next.is_none() || next.unwrap() != ch {
+                    return Ok(Status::Corrupted(ch));
+                }
+            }
+        }
+    }
+    let mut my_complete_score = 0;
+    for ch in stack.iter().rev() {
+        my_complete_score *= 5;
+        my_complete_score += complete_score(*ch).ok_or_else(|| unknown(*ch))?;
+    }
+    return Ok(Status::Incomplete(my_complete_score));
+}
+
+/// The scores of all lines checked so far. Only the completion scores are kept, so arbitrarily
+/// long inputs can be streamed through it.
+#[derive(Default)]
+pub struct Report {
+    pub error_score : u32,
+    pub complete_scores : Vec<u64>
+}
+
+impl Report {
+    pub fn add(&mut self, status : Status) -> Result<(), String> {
+        match status {
+            Status::Corrupted(ch) => { self.error_score += error_score(ch).ok_or_else(|| unknown(ch))?; },
+            Status::Incomplete(score) => { self.complete_scores.push(score); }
+        }
+        return Ok(());
+    }
+
+    /// The middle completion score, if any line is incomplete
+    pub fn middle_score(&self) -> Option<u64> {
+        if self.complete_scores.is_empty() {
+            return None;
+        }
+        let mut complete_scores = self.complete_scores.clone();
+        let middle = complete_scores.len()/2;
+        return Some(*complete_scores.select_nth_unstable(middle).1);
+    }
+}
+
+/// Checks every line. Returns the total error score of the corrupted lines and the middle
+/// completion score of the incomplete ones, if there are any.
+pub fn check<'a>(lines : impl Iterator<Item = &'a str>) -> Result<(u32, Option<u64>), String> {
+    let mut report = Report::default();
+    for line in lines {
+        report.add(check_line(line)?)?;
+    }
+    return Ok((report.error_score, report.middle_score()));
+}
+
+pub struct Door10;
+
+impl Solution for Door10 {
+    type Input = Report;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Report, parse::ParseError> {
+        let mut report = Report::default();
+        loop {
+            match parse::get_next_line(cursor) {
+                // Empty lines, like the one after a final newline, are no chunks at all
+                Ok("") => (),
+                Ok(line) => {
+                    let status = check_line(line).map_err(|message| cursor.error(message))?;
+                    report.add(status).map_err(|message| cursor.error(message))?;
+                },
+                Err(parse::ParseError::Eof{..}) => { break; },
+                Err(err) => { return Err(err); }
+            }
+        }
+        return Ok(report);
+    }
+
+    fn part1(report : &Report) -> Answer<u32> {
+        Ok(report.error_score)
+    }
+
+    fn part2(report : &Report) -> Answer<u64> {
+        Ok(report.middle_score().ok_or("No line is incomplete")?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text : &str) -> Report {
+        Door10::parse(&mut parse::Cursor::new(text.split("\n"))).unwrap()
+    }
+
+    #[test]
+    fn middle_of_the_completion_scores() {
+        let report = parse("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{([(<{}[<>[]}>{[]{[(<()>\n<{([{{}}[<[[[<>{}]]]>[]]\n");
+        assert_eq!(Door10::part1(&report).unwrap(), 1197);
+        assert_eq!(report.complete_scores.len(), 3);
+        assert_eq!(Door10::part2(&report).unwrap(), 5566);
+    }
+
+    #[test]
+    fn empty_lines_are_skipped() {
+        let report = parse("\n[<>\n\n");
+        assert_eq!(report.complete_scores, [2]);
+        assert!(parse("").complete_scores.is_empty());
+    }
+
+    #[test]
+    fn no_middle_without_incomplete_lines() {
+        let report = parse("(]\n");
+        assert_eq!(report.middle_score(), None);
+        assert_eq!(Door10::part1(&report).unwrap(), 57);
+        assert_eq!(Door10::part2(&report).unwrap_err().to_string(), "No line is incomplete");
+    }
+
+    #[test]
+    fn unknown_characters_are_errors() {
+        assert_eq!(check_line("(<x>)"), Err(String::from("Unknown character 'x'")));
+        assert_eq!(check(["[]", "{)"].into_iter()), Ok((3, Some(0))));
+        assert_eq!(check(["[]", "a"].into_iter()), Err(String::from("Unknown character 'a'")));
+        let Err(err) = Door10::parse(&mut parse::Cursor::new("()\n(]\n[?".split("\n"))) else {
+            panic!("? is no bracket");
+        };
+        assert_eq!(err.to_string(), "3:1: Unknown character '?'");
+        assert_eq!(Report::default().add(Status::Corrupted('(')), Err(String::from("Unknown character '('")));
+    }
+}
@@ -0,0 +1,48 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::matrix::Matrix;
+use aoc_common::rng::Rng;
+
+use crate::{round, Door11};
+
+static ROUNDS : usize = 1000;
+static ATTEMPTS : usize = 10;
+
+// Whether all octopuses flash at once within a reasonable number of rounds. Many random grids
+// never do, they end up in a cycle instead.
+fn synchronizes(energylevel : &Matrix<u32>) -> bool {
+    let mut energylevel = energylevel.clone();
+    return (0..ROUNDS).any(|_| round(&mut energylevel) == energylevel.len() as u32);
+}
+
+impl Generator for Door11 {
+    const SIZE : usize = 10;
+
+    /// Writes a square grid of energy levels with side length `size` which synchronizes.
+    ///
+    /// Grids which do not synchronize are thrown away. Since this gets more likely with the size,
+    /// every attempt sets more octopuses to the same level, and a grid of equal levels synchronizes
+    /// in the first rounds.
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        let mut energylevel = Matrix::new(size, size);
+        for attempt in 0..=ATTEMPTS {
+            let common = rng.below(10) as u32;
+            for coords in energylevel.coords_iter() {
+                energylevel[coords] = if rng.below(ATTEMPTS) < attempt { common } else { rng.below(10) as u32 };
+            }
+            if synchronizes(&energylevel) {
+                break;
+            }
+        }
+
+        for y in 0..energylevel.get_height() {
+            if y > 0 {
+                writeln!(out)?;
+            }
+            let row : String = energylevel.row_iter(y).map(|level| char::from_digit(*level, 10).unwrap()).collect();
+            write!(out, "{}", row)?;
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_common::matrix::{Matrix, Stencil};
+use aoc_common::parse;
+use aoc_common::render::{self, Rgb};
+use aoc_common::solution::{Answer, Solution};
+
+mod generate;
+
+fn parse_matrix(cursor : & mut parse::Cursor) -> Result<Matrix<u32>, parse::ParseError> {
+    parse::matrix(cursor, |cursor| parse::characters(cursor, |c| {
+        match c.to_digit(10) {
+            Some(n) => Ok(n),
+            None => Err("Digit was no digit")
+        }
+    }))
+}
+
+static MAX : u32 = 10;
+
+pub fn round(energylevel: &mut Matrix<u32>) -> u32 {
+    let mut flashes = 0;
+    // Step 1
+    for coords in energylevel.coords_iter() {
+        energylevel[coords] += 1;
+    }
+    // Step 2
+    let adjacent = Stencil::moore_without_center(1);
+    let mut will_flash = vec![];
+    for coords in energylevel.coords_iter() {
+        if energylevel[coords] == MAX {
+            will_flash.push(coords);
+        }
+    }
+    while let Some(flashing_coords) = will_flash.pop() {
+        flashes += 1;
+        for coords in energylevel.relativ_coords(flashing_coords, adjacent.offsets()) {
+            energylevel[coords] += 1;
+            if energylevel[coords] == MAX { // i.e. it is now 9
+                will_flash.push(coords);
+            }
+        }
+    }
+    // Step 3
+    for coords in energylevel.coords_iter() {
+        if energylevel[coords] >= MAX {
+            energylevel[coords] = 0;
+        }
+    }
+    return flashes;
+}
+
+pub fn total_flashes(energylevel: &Matrix<u32>, rounds: usize) -> u32 {
+    let mut energylevel = energylevel.clone();
+    let mut total_flashes = 0;
+    for _ in 0..rounds {
+        total_flashes += round(&mut energylevel);
+    }
+    return total_flashes;
+}
+
+/// Returns the first round in which all octopuses flash
+pub fn synchronize(energylevel: &Matrix<u32>) -> usize {
+    let mut energylevel = energylevel.clone();
+    let mut rounds = 1;
+    while round(&mut energylevel) < energylevel.len() as u32 {
+        rounds += 1;
+    }
+    return rounds;
+}
+
+pub struct Door11;
+
+impl Solution for Door11 {
+    type Input = Matrix<u32>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Matrix<u32>, parse::ParseError> {
+        parse_matrix(cursor)
+    }
+
+    fn part1(energylevel : &Matrix<u32>) -> Answer<u32> {
+        Ok(total_flashes(energylevel, 100))
+    }
+
+    fn part2(energylevel : &Matrix<u32>) -> Answer<usize> {
+        Ok(synchronize(energylevel))
+    }
+
+    /// The energy levels after the rounds of part 1
+    fn render(energylevel : &Matrix<u32>) -> Option<Matrix<Rgb>> {
+        let mut energylevel = energylevel.clone();
+        for _ in 0..100 {
+            round(&mut energylevel);
+        }
+        Some(energylevel.map(|level| render::heat(*level as f64 / 9.0)))
+    }
+}
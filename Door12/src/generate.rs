@@ -0,0 +1,51 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door12;
+
+static SMALL : [&str; 3] = ["a", "b", "c"];
+
+impl Generator for Door12 {
+    const SIZE : usize = 12;
+
+    /// Writes a cave system of roughly `size` caves.
+    ///
+    /// The caves form groups of three small caves around a large one, which are only connected via
+    /// start and end. Since large caves are never adjacent, the number of paths is finite, and since
+    /// the groups are small, it only grows linearly with the size.
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        let mut connections = vec!();
+        for group in 0..std::cmp::max(1, size / 4) {
+            let small : Vec<String> = SMALL.iter().map(|name| format!("{}{}", name, group)).collect();
+            let large = format!("X{}", group);
+            for (i, cave) in small.iter().enumerate() {
+                connections.push((large.clone(), cave.clone()));
+                for other in &small[i+1..] {
+                    if rng.one_in(2) {
+                        connections.push((cave.clone(), other.clone()));
+                    }
+                }
+                if i == 0 || rng.one_in(2) {
+                    connections.push((String::from("start"), cave.clone()));
+                }
+                if i == small.len() - 1 || rng.one_in(2) {
+                    connections.push((cave.clone(), String::from("end")));
+                }
+            }
+        }
+        rng.shuffle(&mut connections);
+        for (i, (from, to)) in connections.iter().enumerate() {
+            if i > 0 {
+                writeln!(out)?;
+            }
+            if rng.one_in(2) {
+                write!(out, "{}-{}", from, to)?;
+            } else {
+                write!(out, "{}-{}", to, from)?;
+            }
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,90 @@
+use aoc_common::parse;
+use aoc_common::search::NamedGraph;
+use aoc_common::solution::{Answer, Solution};
+
+mod generate;
+
+fn parse_connections(cursor : & mut parse::Cursor) -> Result<Vec<(String, String)>, parse::ParseError> {
+    parse::all(cursor, |line| {
+        let mut parts = parse::split(line, "-")?;
+        let fst = String::from(parse::get_next_line(&mut parts)?);
+        let snd = String::from(parse::get_next_line(&mut parts)?);
+        return Ok((fst, snd));
+    })
+}
+
+fn dfs(caves: &Caves, visited: &mut Vec<bool>, current: usize) -> (u32, u32) {
+    if current == caves.end {
+        return (1, 1);
+    }
+    if !caves.large[current] {
+        visited[current] = true;
+    }
+    let mut paths = 0;
+    let mut double_paths = 0;
+    for &(other, _) in caves.graph.edges(current) {
+        let recurred_id = visited.len()-1; // Special position to remember whether we repeated a cave once
+        if !visited[other] {
+            let (newpaths, newdoublepaths) = dfs(caves, visited, other);
+            paths += newpaths;
+            double_paths += newdoublepaths;
+        } else if !visited[recurred_id] && other != caves.end && other != caves.start {
+            visited[recurred_id] = true;
+            let (_, newdoublepaths) = dfs(caves, visited, other);
+            double_paths += newdoublepaths;
+            visited[recurred_id] = false;
+            visited[other] = true;
+        }
+    }
+    visited[current] = false;
+    return (paths, double_paths);
+}
+
+pub struct Caves {
+    graph : NamedGraph,
+    // Whether each cave is large and may be visited any number of times
+    large : Vec<bool>,
+    start : usize,
+    end : usize
+}
+
+impl Caves {
+    fn new(connections : &[(String, String)]) -> Option<Caves> {
+        let mut graph = NamedGraph::new();
+        for (from, to) in connections {
+            graph.connect(from, to, 1);
+        }
+        let large = (0..graph.len()).map(|id| graph.name(id).starts_with(|c : char| c.is_uppercase())).collect();
+        let start = graph.id("start")?;
+        let end = graph.id("end")?;
+        return Some(Caves{graph: graph, large: large, start: start, end: end});
+    }
+}
+
+/// Returns the number of paths which visit small caves at most once and the number of paths
+/// which may visit a single small cave twice
+pub fn count_paths(caves : &Caves) -> (u32, u32) {
+    let mut visited = vec![false; caves.graph.len()+1];
+    return dfs(caves, &mut visited, caves.start);
+}
+
+pub struct Door12;
+
+impl Solution for Door12 {
+    type Input = Caves;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Caves, parse::ParseError> {
+        let connections = parse_connections(cursor)?;
+        return Caves::new(&connections).ok_or_else(|| cursor.error("Missing start or end cave"));
+    }
+
+    fn part1(caves : &Caves) -> Answer<u32> {
+        Ok(count_paths(caves).0)
+    }
+
+    fn part2(caves : &Caves) -> Answer<u32> {
+        Ok(count_paths(caves).1)
+    }
+}
@@ -0,0 +1,52 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door13;
+
+// The folds in reverse, i.e., starting from the code, true for folds along x
+static UNFOLDS : [bool; 12] = [false, false, false, true, false, true, false, true, false, true, false, true];
+static WIDTH : u32 = 40;
+static HEIGHT : u32 = 6;
+
+impl Generator for Door13 {
+    const SIZE : usize = 800;
+
+    /// Writes `size` points and the folds which stack them onto a 40 by 6 code.
+    ///
+    /// The points are placed in the code and then unfolded, i.e., mirrored at random at every fold
+    /// line, so no point ever lies on a fold line.
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        let mut folds = vec!();
+        let (mut width, mut height) = (WIDTH, HEIGHT);
+        for along_x in UNFOLDS {
+            if along_x {
+                folds.push((along_x, width));
+                width = 2*width + 1;
+            } else {
+                folds.push((along_x, height));
+                height = 2*height + 1;
+            }
+        }
+
+        for _ in 0..size {
+            let mut x = rng.below(WIDTH as usize) as u32;
+            let mut y = rng.below(HEIGHT as usize) as u32;
+            for (along_x, line) in &folds {
+                if rng.one_in(2) {
+                    if *along_x {
+                        x = 2*line - x;
+                    } else {
+                        y = 2*line - y;
+                    }
+                }
+            }
+            writeln!(out, "{},{}", x, y)?;
+        }
+        for (along_x, line) in folds.iter().rev() {
+            write!(out, "\nfold along {}={}", if *along_x { "x" } else { "y" }, line)?;
+        }
+        return Ok(());
+    }
+}
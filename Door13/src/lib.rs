@@ -0,0 +1,155 @@
+use aoc_common::matrix::Matrix;
+use aoc_common::parse;
+use aoc_common::render::{self, Rgb};
+use aoc_common::solution::{Answer, Solution};
+
+mod generate;
+
+#[derive(Debug)]
+pub enum FoldDirection { Down, Left }
+
+pub type Fold = (FoldDirection, u32);
+
+pub struct Manual {
+    pub paper : Matrix<bool>,
+    pub folds : Vec<Fold>
+}
+
+/// Folds the part of the paper after the fold line onto the part before it
+pub fn fold(paper : &Matrix<bool>, (direction, coordinate) : &Fold) -> Matrix<bool> {
+    match direction {
+        FoldDirection::Down => fold_up(paper, *coordinate as usize),
+        FoldDirection::Left => fold_up(&paper.transpose(), *coordinate as usize).transpose()
+    }
+}
+
+// Folds the part below row `line` up. The bottom part is flipped and laid over the top part such
+// that the rows next to the fold line meet.
+fn fold_up(paper : &Matrix<bool>, line : usize) -> Matrix<bool> {
+    let width = paper.get_width();
+    let below = paper.get_height() - line - 1;
+    assert!(below <= line, "Folding along {} would extend the paper", line);
+    assert!(paper.row(line).iter().all(|dot| !dot), "Dot on the fold line {}", line);
+
+    let mut folded = paper.sub_matrix(0, 0, width, line).to_matrix();
+    let bottom = paper.sub_matrix(0, line+1, width, below).to_matrix().flip_vertical();
+    let mut overlap = folded.sub_matrix_mut(0, line-below, width, below);
+    for coords in bottom.coords_iter() {
+        overlap[coords] |= bottom[coords];
+    }
+    return folded;
+}
+
+/// Draws the dots as lines of '#' and ' ', cropped to the area they cover
+pub fn render(paper : &Matrix<bool>) -> String {
+    // Determine size
+    let mut width = 0;
+    let mut height = 0;
+    for coords in paper.coords_iter() {
+        if paper[coords] {
+            let (x, y) = *coords;
+            width = std::cmp::max(width, x+1);
+            height = std::cmp::max(height, y+1);
+        }
+    }
+
+    return render::text(&paper.sub_matrix(0, 0, width, height).to_matrix().map(|dot| if *dot { '#' } else { ' ' }));
+}
+
+fn parse_fold(cursor : & mut parse::Cursor) -> Result<Fold, parse::ParseError> {
+    let line = String::from(parse::get_next_line(cursor)?);
+    let Some((direction, coordinate)) = line.split_once('=') else {
+        return Err(cursor.error("Expected a fold like fold along y=7"));
+    };
+    let direction = match direction {
+        "fold along y" => FoldDirection::Down,
+        "fold along x" => FoldDirection::Left,
+        _ => { return Err(cursor.error(format!("Unknown fold direction {}", direction))); }
+    };
+    let coordinate = coordinate.parse::<u32>()
+        .map_err(|err| parse::ParseError::Inner{at: cursor.position(), source: err.into()})?;
+    return Ok((direction, coordinate));
+}
+
+pub struct Door13;
+
+impl Solution for Door13 {
+    type Input = Manual;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(cursor : & mut parse::Cursor) -> Result<Manual, parse::ParseError> {
+        let points : Vec<(usize,usize)> = parse::all(cursor, parse::tuple::<usize>)?;
+        let mut folds = vec!();
+        while !cursor.at_end() {
+            folds.push(parse_fold(cursor)?);
+        }
+
+        // The paper reaches at least to the dots, and the fold lines lie in its middle
+        let mut width = 0;
+        let mut height = 0;
+        for (x, y) in &points {
+            width = std::cmp::max(width, x+1);
+            height = std::cmp::max(height, y+1);
+        }
+        for (direction, line) in &folds {
+            let size = 2*(*line as usize) + 1;
+            match direction {
+                FoldDirection::Left => { width = std::cmp::max(width, size); },
+                FoldDirection::Down => { height = std::cmp::max(height, size); }
+            }
+        }
+        let mut paper = Matrix::new(width, height);
+        for (x, y) in points {
+            paper.set(x, y, true);
+        }
+        return Ok(Manual{paper: paper, folds: folds});
+    }
+
+    fn part1(manual : &Manual) -> Answer<usize> {
+        let first = manual.folds.first().ok_or("The manual has no folds")?;
+        Ok(fold(&manual.paper, first).iter().filter(|dot| **dot).count())
+    }
+
+    fn part2(manual : &Manual) -> Answer<String> {
+        let paper = manual.folds.iter().fold(manual.paper.clone(), |paper, instruction| fold(&paper, instruction));
+        Ok(render(&paper))
+    }
+
+    fn render(manual : &Manual) -> Option<Matrix<Rgb>> {
+        let paper = manual.folds.iter().fold(manual.paper.clone(), |paper, instruction| fold(&paper, instruction));
+        Some(paper.map(|dot| if *dot { Rgb(255, 255, 255) } else { Rgb(0, 0, 0) }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text : &str) -> Result<Manual, parse::ParseError> {
+        Door13::parse(&mut parse::Cursor::new(text.split("\n")))
+    }
+
+    #[test]
+    fn dots_and_folds() {
+        let manual = parse("0,0\n4,1\n\nfold along x=2\nfold along y=1\n").unwrap();
+        assert_eq!((manual.paper.get_width(), manual.paper.get_height()), (5, 3));
+        assert!(matches!(manual.folds[..], [(FoldDirection::Left, 2), (FoldDirection::Down, 1)]));
+        assert_eq!(Door13::part1(&manual).unwrap(), 2);
+    }
+
+    #[test]
+    fn broken_folds_are_errors() {
+        let message = |text| parse(text).err().map(|err| err.to_string());
+        assert_eq!(message("1,1\n\nfold along z=3").unwrap(), "3:1: Unknown fold direction fold along z");
+        assert_eq!(message("1,1\n\nfold along y").unwrap(), "3:1: Expected a fold like fold along y=7");
+        assert_eq!(message("1,1\n\nfold along x=2\nfold along y=-1").unwrap(), "4:1: invalid digit found in string");
+    }
+
+    #[test]
+    fn part1_needs_a_fold() {
+        let manual = parse("1,1\n").unwrap();
+        assert!(manual.folds.is_empty());
+        assert_eq!(Door13::part1(&manual).unwrap_err().to_string(), "The manual has no folds");
+    }
+}
@@ -0,0 +1,25 @@
+use std::io::{self, Write};
+
+use aoc_common::generator::Generator;
+use aoc_common::rng::Rng;
+
+use crate::Door14;
+
+static ELEMENTS : [char; 10] = ['B', 'C', 'F', 'H', 'K', 'N', 'O', 'P', 'S', 'V'];
+
+impl Generator for Door14 {
+    const SIZE : usize = 20;
+
+    /// Writes a polymer template of `size` elements and an insertion rule for every pair of
+    /// elements
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()> {
+        let template : String = (0..size).map(|_| *rng.choose(&ELEMENTS)).collect();
+        writeln!(out, "{}", template)?;
+        for first in ELEMENTS {
+            for second in ELEMENTS {
+                write!(out, "\n{}{} -> {}", first, second, rng.choose(&ELEMENTS))?;
+            }
+        }
+        return Ok(());
+    }
+}
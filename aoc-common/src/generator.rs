@@ -0,0 +1,15 @@
+use std::io::{self, Write};
+
+use crate::rng::Rng;
+
+/// Writes random inputs in the format of a door's puzzle input, e.g., for stress tests and
+/// benchmarks. Every generated input is valid, i.e., the door's [`Solution`](crate::solution::Solution)
+/// can solve it.
+pub trait Generator {
+    /// The size of the real puzzle input
+    const SIZE : usize;
+
+    /// Writes an input of the given size. What the size counts depends on the puzzle, e.g., lines
+    /// or the side length of a map.
+    fn generate(rng : & mut Rng, size : usize, out : & mut dyn Write) -> io::Result<()>;
+}
@@ -0,0 +1,117 @@
+use std::hash::Hash;
+use std::io::{self, Write};
+
+use crate::matrix::{Coordinates, Matrix, Stencil};
+use crate::render::{self, Rgb};
+
+/// Entries which can be drawn as a single character
+pub trait Cell {
+    fn to_char(&self) -> char;
+}
+
+impl Cell for bool {
+    fn to_char(&self) -> char { if *self { '#' } else { '.' } }
+}
+
+impl Cell for char {
+    fn to_char(&self) -> char { *self }
+}
+
+// Digits are drawn as such, larger numbers as '+' and negative ones as '-'
+macro_rules! number_cell {
+    ($($type:ty),*) => {
+        $(
+            impl Cell for $type {
+                #[allow(unused_comparisons)]
+                fn to_char(&self) -> char {
+                    if *self < 0 {
+                        '-'
+                    } else if *self > 9 {
+                        '+'
+                    } else {
+                        char::from_digit(*self as u32, 10).unwrap()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+number_cell!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// The operations dense [`Matrix`]es and [`SparseGrid`](crate::sparse::SparseGrid)s have in
+/// common, so algorithms can run on either
+pub trait Grid {
+    type Value;
+    type Position : Copy + Eq + Hash;
+
+    /// The entry at `position`, or None if the position is outside of the grid
+    fn get(&self, position : Self::Position) -> Option<&Self::Value>;
+
+    /// Stores `value` at `position`, which must be inside the grid
+    fn set(&mut self, position : Self::Position, value : Self::Value);
+
+    /// The positions around `position` in the shape of `stencil` that are inside the grid
+    fn neighbors<'a>(&self, stencil : &'a Stencil, position : Self::Position) -> impl Iterator<Item = Self::Position> + 'a;
+
+    /// The top left and bottom right corner of the area that holds entries, or None if there are
+    /// none
+    fn bounds(&self) -> Option<(Self::Position, Self::Position)>;
+
+    /// The area within the bounds as a matrix, with `f` applied to every entry, or None if the
+    /// area is too large to be indexed
+    fn rasterize<B>(&self, f : impl FnMut(&Self::Value) -> B) -> Option<Matrix<B>>;
+
+    /// Draws the area within the bounds one row per line, every entry as the character `draw`
+    /// maps it to
+    fn render_with(&self, draw : impl FnMut(&Self::Value) -> char) -> Option<String> {
+        self.rasterize(draw).map(|characters| render::text(&characters))
+    }
+
+    fn render(&self) -> Option<String> where Self::Value : Cell {
+        self.render_with(Cell::to_char)
+    }
+
+    /// Draws the area within the bounds for a terminal with truecolor support, see
+    /// [`render::heat`] for a color scale
+    fn render_ansi(&self, color : impl FnMut(&Self::Value) -> Rgb) -> Option<String> {
+        self.rasterize(color).map(|image| render::ansi(&image))
+    }
+
+    /// Writes the area within the bounds as PNG, every entry as a square of `scale` pixels
+    fn write_png(&self, out : impl Write, scale : usize, color : impl FnMut(&Self::Value) -> Rgb) -> io::Result<()> {
+        let image = self.rasterize(color)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "The grid is too large to draw"))?;
+        return render::png(&image, scale, out);
+    }
+}
+
+impl<A> Grid for Matrix<A> {
+    type Value = A;
+    type Position = Coordinates;
+
+    fn get(&self, position : Coordinates) -> Option<&A> {
+        let (x, y) = *position;
+        if x < self.get_width() && y < self.get_height() { Some(&self[position]) } else { None }
+    }
+
+    fn set(&mut self, position : Coordinates, value : A) {
+        let (x, y) = *position;
+        Matrix::set(self, x, y, value);
+    }
+
+    fn neighbors<'a>(&self, stencil : &'a Stencil, position : Coordinates) -> impl Iterator<Item = Coordinates> + 'a {
+        self.relativ_coords(position, stencil.offsets())
+    }
+
+    fn bounds(&self) -> Option<(Coordinates, Coordinates)> {
+        if self.is_empty() {
+            return None;
+        }
+        return Some((Coordinates((0, 0)), Coordinates((self.get_width()-1, self.get_height()-1))));
+    }
+
+    fn rasterize<B>(&self, f : impl FnMut(&A) -> B) -> Option<Matrix<B>> {
+        Some(self.map(f))
+    }
+}
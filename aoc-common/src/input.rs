@@ -0,0 +1,88 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+use flate2::read::MultiGzDecoder;
+
+/// Opens the input `name` for buffered reading. `-` reads from stdin and files ending in `.gz`
+/// are decompressed on the fly, so neither has to fit into memory.
+pub fn open(name : &str) -> io::Result<Box<dyn BufRead>> {
+    if name == "-" {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    let file = File::open(name)?;
+    if name.ends_with(".gz") {
+        return Ok(Box::new(BufReader::new(MultiGzDecoder::new(file))));
+    }
+    return Ok(Box::new(BufReader::new(file)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::path::PathBuf;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    // A file in the temporary directory that is removed again at the end of the test
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name : &str, contents : &[u8]) -> TempFile {
+            let path = std::env::temp_dir().join(format!("aoc-input-{}-{}", std::process::id(), name));
+            std::fs::write(&path, contents).unwrap();
+            return TempFile(path);
+        }
+
+        fn name(&self) -> &str { self.0.to_str().unwrap() }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) { let _ = std::fs::remove_file(&self.0); }
+    }
+
+    fn gzip(contents : &str) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(vec!(), Compression::default());
+        encoder.write_all(contents.as_bytes()).unwrap();
+        return encoder.finish().unwrap();
+    }
+
+    fn read(name : &str) -> String {
+        let mut contents = String::new();
+        open(name).unwrap().read_to_string(&mut contents).unwrap();
+        return contents;
+    }
+
+    #[test]
+    fn plain_files() {
+        let file = TempFile::new("plain.txt", b"1\n2\n");
+        assert_eq!(read(file.name()), "1\n2\n");
+    }
+
+    #[test]
+    fn gzipped_files() {
+        let file = TempFile::new("single.txt.gz", &gzip("199\n200\n"));
+        assert_eq!(read(file.name()), "199\n200\n");
+        // Concatenated gzip files, as written by `cat a.gz b.gz`, decompress to both contents
+        let mut members = gzip("1\n");
+        members.extend(gzip("2\n"));
+        let file = TempFile::new("members.txt.gz", &members);
+        assert_eq!(read(file.name()), "1\n2\n");
+    }
+
+    #[test]
+    fn only_gz_names_are_decompressed() {
+        let file = TempFile::new("packed.txt", &gzip("1\n"));
+        let mut contents = vec!();
+        open(file.name()).unwrap().read_to_end(&mut contents).unwrap();
+        assert_eq!(contents, gzip("1\n"));
+    }
+
+    #[test]
+    fn missing_files_and_stdin() {
+        let err = open("/nonexistent/input.txt").err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(open("-").is_ok());
+    }
+}
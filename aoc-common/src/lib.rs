@@ -0,0 +1,12 @@
+pub mod generator;
+pub mod grid;
+pub mod input;
+pub mod matrix;
+pub mod parse;
+pub mod present;
+pub mod render;
+pub mod rng;
+pub mod search;
+pub mod solution;
+pub mod sparse;
+pub mod top;
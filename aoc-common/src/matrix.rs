@@ -0,0 +1,555 @@
+mod components;
+mod stencil;
+mod view;
+
+pub use components::ComponentInfo;
+pub use stencil::Stencil;
+pub use view::{ColumnView, ColumnViewMut, MatrixView, MatrixViewMut, Rows};
+
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+pub struct Coordinates<T = usize>(pub (T, T));
+
+pub type RelativeCoordinates = Coordinates<isize>;
+
+impl<T> std::ops::Deref for Coordinates<T> {
+    type Target = (T, T);
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl<T:PartialOrd+Copy> Coordinates<T> {
+    pub fn within(&self, l: T, r: T, b: T, t: T) -> bool {
+        let (x,y) = **self;
+        return x >= l && x <= r && y >= b && y <= t;
+    }
+}
+
+impl RelativeCoordinates {
+    pub fn to_coordinates(&self) -> Coordinates {
+        let (x,y) = **self;
+        assert!(x >= 0 && y >= 0);
+        return Coordinates((x as usize, y as usize));
+    }
+
+    /// The coordinates on a `width` x `height` torus, i.e., coordinates past one edge continue at
+    /// the opposite one
+    pub fn wrap(&self, width: usize, height: usize) -> Coordinates {
+        let (x,y) = **self;
+        return Coordinates((x.rem_euclid(width as isize) as usize, y.rem_euclid(height as isize) as usize));
+    }
+}
+
+/// The direct neighbors, see [`Matrix::neighbor_coords`]
+pub static NEIGHBORS : [RelativeCoordinates; 4]
+    = [Coordinates((-1, 0)), Coordinates((1, 0)), Coordinates((0, -1)), Coordinates((0, 1))];
+
+/// The 3 x 3 square around a position including the position itself, see [`Matrix::around_coords`]
+pub static AROUND : [RelativeCoordinates; 9] = [Coordinates((-1,  0)), Coordinates((0,  0)), Coordinates((1,  0)),
+                                                Coordinates((-1, -1)), Coordinates((0, -1)), Coordinates((1, -1)),
+                                                Coordinates((-1,  1)), Coordinates((0,  1)), Coordinates((1,  1))];
+
+/// How neighbor queries treat positions outside of the matrix
+#[derive(Clone, Debug, Default)]
+pub enum Boundary<A> {
+    /// Leave them out
+    #[default]
+    Clip,
+    /// Continue at the opposite edge, as if the matrix was the surface of a torus
+    Wrap,
+    /// Treat them as if they held the given value. Such positions have no coordinates, so queries
+    /// for coordinates leave them out.
+    Pad(A)
+}
+
+impl std::ops::Add<RelativeCoordinates> for Coordinates {
+    type Output = RelativeCoordinates;
+
+    fn add(self, rhs: RelativeCoordinates) -> RelativeCoordinates {
+        let (x, y) = *self;
+        let (rel_x, rel_y) = *rhs;
+        assert!(x <= isize::MAX as usize && y <= isize::MAX as usize);
+        let sum_x = (x as isize)+rel_x;
+        let sum_y = (y as isize)+rel_y;
+        return Coordinates((sum_x, sum_y));
+    }
+}
+
+#[derive(Clone)]
+pub struct Matrix<A> {
+    height: usize,
+    width: usize,
+    data: Vec<A>
+}
+
+impl<A> Matrix<A> {
+    pub fn new_from_row(row: Vec<A>) -> Matrix<A> {
+        return Matrix{width: row.len(), height: 1, data: row};
+    }
+
+    /// Creates a matrix with `f(x, y)` at every position
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> A) -> Matrix<A> {
+        let mut data = Vec::with_capacity(width*height);
+        for y in 0..height {
+            for x in 0..width {
+                data.push(f(x, y));
+            }
+        }
+        return Matrix{width: width, height: height, data: data};
+    }
+
+    pub fn get_width(&self) -> usize { self.width }
+    pub fn get_height(&self) -> usize { self.height }
+    pub fn len(&self) -> usize { self.width * self.height }
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    fn calc_coordinates(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height);
+        y*self.width + x
+    }
+    pub fn get(&self, x: usize, y: usize) -> &A { &self.data[self.calc_coordinates(x, y)] }
+    pub fn set(&mut self , x: usize, y: usize, val: A) {
+        let index = self.calc_coordinates(x,y);
+        self.data[index] = val;
+    }
+
+    pub fn append_row(&mut self, row: &mut Vec<A>) {
+        assert!(row.len() == self.width);
+        self.data.append(row);
+        self.height += 1;
+    }
+
+    pub fn append_all(&mut self, rows: &mut Vec<Vec<A>>) {
+        self.data.reserve(rows.len() * self.width);
+        for row in rows {
+            self.append_row(row);
+        }
+    }
+
+    pub fn map<B>(&self, f: impl FnMut(&A) -> B) -> Matrix<B> {
+        return Matrix{width: self.width, height: self.height, data: self.data.iter().map(f).collect()};
+    }
+
+    /// Combines the entries at the same position of two matrices of the same size
+    pub fn zip_with<B, C>(&self, other: &Matrix<B>, mut f: impl FnMut(&A, &B) -> C) -> Matrix<C> {
+        assert!(self.width == other.width && self.height == other.height);
+        let data = self.data.iter().zip(other.data.iter()).map(|(a, b)| f(a, b)).collect();
+        return Matrix{width: self.width, height: self.height, data: data};
+    }
+}
+
+impl<A: Clone> Matrix<A> {
+    // A matrix of the given size which takes the entry at (x, y) from `source(x, y)` in this one
+    fn rearrange(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> (usize, usize)) -> Matrix<A> {
+        Matrix::from_fn(width, height, |x, y| {
+            let (x, y) = source(x, y);
+            self.get(x, y).clone()
+        })
+    }
+
+    /// Mirrors the matrix along its main diagonal, i.e., rows become columns
+    pub fn transpose(&self) -> Matrix<A> {
+        self.rearrange(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotates the matrix by 90° clockwise
+    pub fn rotate_cw(&self) -> Matrix<A> {
+        let height = self.height;
+        self.rearrange(self.height, self.width, |x, y| (y, height-1-x))
+    }
+
+    /// Rotates the matrix by 90° counterclockwise
+    pub fn rotate_ccw(&self) -> Matrix<A> {
+        let width = self.width;
+        self.rearrange(self.height, self.width, |x, y| (width-1-y, x))
+    }
+
+    /// Mirrors the matrix left to right
+    pub fn flip_horizontal(&self) -> Matrix<A> {
+        let width = self.width;
+        self.rearrange(self.width, self.height, |x, y| (width-1-x, y))
+    }
+
+    /// Mirrors the matrix top to bottom
+    pub fn flip_vertical(&self) -> Matrix<A> {
+        let height = self.height;
+        self.rearrange(self.width, self.height, |x, y| (x, height-1-y))
+    }
+}
+
+impl<A> Matrix<A> {
+    pub fn row(&self, y: usize) -> &[A] {
+        assert!(y < self.height);
+        &self.data[y*self.width..(y+1)*self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [A] {
+        assert!(y < self.height);
+        &mut self.data[y*self.width..(y+1)*self.width]
+    }
+
+    pub fn row_iter(&self, y: usize) -> std::slice::Iter<'_, A> {
+        self.row(y).iter()
+    }
+
+    pub fn column(&self, x: usize) -> ColumnView<'_, A> {
+        assert!(x < self.width);
+        ColumnView::new(&self.data[view::region(self.width, x, 0, 1, self.height)], self.width)
+    }
+
+    pub fn column_mut(&mut self, x: usize) -> ColumnViewMut<'_, A> {
+        assert!(x < self.width);
+        let region = view::region(self.width, x, 0, 1, self.height);
+        ColumnViewMut::new(&mut self.data[region], self.width)
+    }
+
+    pub fn col_iter(&self, x: usize) -> std::iter::StepBy<std::slice::Iter<'_, A>> {
+        self.column(x).iter()
+    }
+
+    /// Borrows the `width` x `height` region with (`x`, `y`) as its top left corner
+    pub fn sub_matrix(&self, x: usize, y: usize, width: usize, height: usize) -> MatrixView<'_, A> {
+        assert!(x + width <= self.width && y + height <= self.height);
+        MatrixView::new(&self.data[view::region(self.width, x, y, width, height)], self.width, width, height)
+    }
+
+    pub fn sub_matrix_mut(&mut self, x: usize, y: usize, width: usize, height: usize) -> MatrixViewMut<'_, A> {
+        assert!(x + width <= self.width && y + height <= self.height);
+        let region = view::region(self.width, x, y, width, height);
+        MatrixViewMut::new(&mut self.data[region], self.width, width, height)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, A> {
+        self.data.iter()
+    }
+
+    pub fn coords_iter(&self) -> CoordsIter {
+        CoordsIter{height: self.height, next: 0, end: self.len()}
+    }
+
+    pub fn get_all<I: Iterator<Item = Coordinates>>(&self, iter: I) -> Values<'_, A, I> {
+        Values{matrix: self, coords: iter}
+    }
+
+    pub fn relativ_coords<'a>(&self, origin: Coordinates, neighbors: &'a [RelativeCoordinates]) -> Neighbors<'a> {
+        self.relativ_coords_with(origin, neighbors, &Boundary::Clip)
+    }
+
+    pub fn relativ_coords_with<'a>(&self, origin: Coordinates, neighbors: &'a [RelativeCoordinates], boundary: &Boundary<A>) -> Neighbors<'a> {
+        let wrap = matches!(boundary, Boundary::Wrap);
+        Neighbors{origin: origin, offsets: neighbors.iter(), width: self.width, height: self.height, wrap: wrap}
+    }
+
+    pub fn neighbor_coords(&self, origin: Coordinates) -> Neighbors<'static> {
+        self.relativ_coords(origin, &NEIGHBORS)
+    }
+
+    pub fn around_coords(&self, origin: Coordinates) -> Neighbors<'static> {
+        self.relativ_coords(origin, &AROUND)
+    }
+
+    /// The neighbors of `origin` within the matrix in the shape of `stencil`, with their entries
+    pub fn neighbors_with<'m, 's>(&'m self, stencil: &'s Stencil, origin: Coordinates) -> Entries<'m, A, Neighbors<'s>> {
+        Entries{matrix: self, coords: self.relativ_coords(origin, stencil.offsets())}
+    }
+
+    /// The entries at the given offsets from `origin`, positions outside are handled according to
+    /// `boundary`
+    pub fn relativ_values<'m, 'o>(&'m self, origin: Coordinates, neighbors: &'o [RelativeCoordinates], boundary: &'m Boundary<A>) -> NeighborValues<'m, 'o, A> {
+        NeighborValues{matrix: self, origin: origin, offsets: neighbors.iter(), boundary: boundary}
+    }
+
+    pub fn neighbor_values<'m>(&'m self, origin: Coordinates, boundary: &'m Boundary<A>) -> NeighborValues<'m, 'static, A> {
+        self.relativ_values(origin, &NEIGHBORS, boundary)
+    }
+
+    pub fn around_values<'m>(&'m self, origin: Coordinates, boundary: &'m Boundary<A>) -> NeighborValues<'m, 'static, A> {
+        self.relativ_values(origin, &AROUND, boundary)
+    }
+}
+
+/// All coordinates of a matrix, column by column, see [`Matrix::coords_iter`]
+pub struct CoordsIter {
+    height: usize,
+    next: usize,
+    end: usize
+}
+
+impl Iterator for CoordsIter {
+    type Item = Coordinates;
+
+    fn next(&mut self) -> Option<Coordinates> {
+        if self.next == self.end {
+            return None;
+        }
+        let coords = Coordinates((self.next / self.height, self.next % self.height));
+        self.next += 1;
+        return Some(coords);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.next;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CoordsIter {}
+
+/// The entries at the given coordinates, see [`Matrix::get_all`]
+pub struct Values<'a, A, I> {
+    matrix: &'a Matrix<A>,
+    coords: I
+}
+
+impl<'a, A, I: Iterator<Item = Coordinates>> Iterator for Values<'a, A, I> {
+    type Item = &'a A;
+
+    fn next(&mut self) -> Option<&'a A> {
+        self.coords.next().map(|coords| &self.matrix[coords])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.coords.size_hint() }
+}
+
+impl<A, I: ExactSizeIterator<Item = Coordinates>> ExactSizeIterator for Values<'_, A, I> {}
+
+/// Coordinates together with their entries, see [`Matrix::neighbors_with`]
+pub struct Entries<'m, A, I> {
+    matrix: &'m Matrix<A>,
+    coords: I
+}
+
+impl<'m, A, I: Iterator<Item = Coordinates>> Iterator for Entries<'m, A, I> {
+    type Item = (Coordinates, &'m A);
+
+    fn next(&mut self) -> Option<(Coordinates, &'m A)> {
+        self.coords.next().map(|coords| (coords, &self.matrix[coords]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.coords.size_hint() }
+}
+
+/// The coordinates at the given offsets from an origin which lie within the matrix or, if it wraps,
+/// their wrapped counterparts, see [`Matrix::relativ_coords_with`]
+pub struct Neighbors<'a> {
+    origin: Coordinates,
+    offsets: std::slice::Iter<'a, RelativeCoordinates>,
+    width: usize,
+    height: usize,
+    wrap: bool
+}
+
+impl Iterator for Neighbors<'_> {
+    type Item = Coordinates;
+
+    fn next(&mut self) -> Option<Coordinates> {
+        let width = self.width as isize;
+        let height = self.height as isize;
+        for offset in self.offsets.by_ref() {
+            let coords = self.origin + *offset;
+            if coords.within(0, width-1, 0, height-1) {
+                return Some(coords.to_coordinates());
+            } else if self.wrap {
+                return Some(coords.wrap(self.width, self.height));
+            }
+        }
+        return None;
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.offsets.size_hint();
+        if self.wrap { (lower, upper) } else { (0, upper) }
+    }
+}
+
+/// The entries at the given offsets from an origin, see [`Matrix::relativ_values`]
+pub struct NeighborValues<'m, 'o, A> {
+    matrix: &'m Matrix<A>,
+    origin: Coordinates,
+    offsets: std::slice::Iter<'o, RelativeCoordinates>,
+    boundary: &'m Boundary<A>
+}
+
+impl<'m, A> Iterator for NeighborValues<'m, '_, A> {
+    type Item = &'m A;
+
+    fn next(&mut self) -> Option<&'m A> {
+        let width = self.matrix.width as isize;
+        let height = self.matrix.height as isize;
+        for offset in self.offsets.by_ref() {
+            let coords = self.origin + *offset;
+            if coords.within(0, width-1, 0, height-1) {
+                return Some(&self.matrix[coords.to_coordinates()]);
+            }
+            match self.boundary {
+                Boundary::Clip => {},
+                Boundary::Wrap => { return Some(&self.matrix[coords.wrap(self.matrix.width, self.matrix.height)]); },
+                Boundary::Pad(value) => { return Some(value); }
+            }
+        }
+        return None;
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.offsets.size_hint();
+        match self.boundary {
+            Boundary::Clip => (0, upper),
+            _ => (lower, upper)
+        }
+    }
+}
+
+impl<A:Clone+Copy> Matrix<A> {
+    pub fn new_with_init(width: usize, height: usize, init: &A) -> Matrix<A> {
+        return Matrix{width: width, height: height, data: vec![*init; width*height]}
+    }
+}
+
+impl<A:Default + Clone + Copy> Matrix<A> {
+    pub fn new(width: usize, height: usize) -> Matrix<A> {
+        return Matrix::new_with_init(width, height, &A::default());
+    }
+}
+
+impl<A> std::ops::Index<Coordinates> for Matrix<A> {
+    type Output = A;
+
+    fn index(&self, coords: Coordinates) -> &Self::Output {
+        let (x, y) = *coords;
+        return self.get(x, y);
+    }
+}
+
+impl<A> std::ops::IndexMut<Coordinates> for Matrix<A> {
+    fn index_mut(&mut self, coords: Coordinates) -> &mut Self::Output {
+        let (x, y) = *coords;
+        let index = self.calc_coordinates(x, y);
+        return &mut self.data[index];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A `width` x `height` matrix with 10*y + x at (x, y)
+    fn numbered(width: usize, height: usize) -> Matrix<usize> {
+        Matrix::from_fn(width, height, |x, y| 10*y + x)
+    }
+
+    fn rows<A: Copy>(matrix: &Matrix<A>) -> Vec<Vec<A>> {
+        (0..matrix.get_height()).map(|y| matrix.row(y).to_vec()).collect()
+    }
+
+    #[test]
+    fn coords_iter_goes_column_by_column() {
+        let matrix = numbered(3, 2);
+        let coords = matrix.coords_iter();
+        assert_eq!(coords.len(), 6);
+        let values : Vec<usize> = matrix.get_all(coords).copied().collect();
+        assert_eq!(values, [0, 10, 1, 11, 2, 12]);
+        assert_eq!(matrix.get_all(matrix.coords_iter().skip(4)).len(), 2);
+    }
+
+    #[test]
+    fn neighbors_within_the_matrix() {
+        let matrix = numbered(3, 3);
+        let corner : Vec<_> = matrix.neighbor_coords(Coordinates((0, 0))).map(|c| c.0).collect();
+        assert_eq!(corner, [(1, 0), (0, 1)]);
+        assert_eq!(matrix.neighbor_coords(Coordinates((1, 1))).count(), 4);
+        assert_eq!(matrix.neighbor_coords(Coordinates((0, 0))).size_hint(), (0, Some(4)));
+        assert_eq!(matrix.around_coords(Coordinates((1, 1))).count(), 9);
+        assert_eq!(matrix.around_coords(Coordinates((2, 2))).count(), 4);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let matrix = numbered(3, 3);
+        assert_eq!(matrix.row_iter(1).copied().collect::<Vec<_>>(), [10, 11, 12]);
+        let column = matrix.col_iter(2);
+        assert_eq!(column.len(), 3);
+        assert_eq!(column.copied().collect::<Vec<_>>(), [2, 12, 22]);
+    }
+
+    #[test]
+    fn col_iter_follows_the_height() {
+        let wide = numbered(3, 2);
+        assert_eq!(wide.col_iter(2).copied().collect::<Vec<_>>(), [2, 12]);
+        let tall = numbered(2, 3);
+        assert_eq!(tall.col_iter(1).copied().collect::<Vec<_>>(), [1, 11, 21]);
+    }
+
+    #[test]
+    fn rows_and_columns_of_non_square_matrices() {
+        let mut matrix = numbered(3, 2);
+        assert_eq!(matrix.row(1), [10, 11, 12]);
+        assert_eq!(matrix.column(1).len(), 2);
+        assert_eq!(matrix.column(1)[1], 11);
+        assert_eq!(matrix.column(1).get(2), None);
+
+        matrix.row_mut(0)[2] = 99;
+        matrix.column_mut(0)[1] = 42;
+        assert_eq!(rows(&matrix), [vec![0, 1, 99], vec![42, 11, 12]]);
+    }
+
+    #[test]
+    fn rotations_of_a_non_square_matrix() {
+        let matrix = numbered(3, 2);
+        let cw = matrix.rotate_cw();
+        assert_eq!(rows(&cw), [vec![10, 0], vec![11, 1], vec![12, 2]]);
+        assert_eq!(rows(&matrix.rotate_ccw()), [vec![2, 12], vec![1, 11], vec![0, 10]]);
+        assert_eq!(rows(&cw.rotate_ccw()), rows(&matrix));
+        assert_eq!(rows(&matrix.rotate_ccw().rotate_cw()), rows(&matrix));
+        assert_eq!(rows(&cw.rotate_cw().rotate_cw().rotate_cw()), rows(&matrix));
+    }
+
+    #[test]
+    fn transpose_and_flips() {
+        let matrix = numbered(3, 2);
+        assert_eq!(rows(&matrix.transpose()), [vec![0, 10], vec![1, 11], vec![2, 12]]);
+        assert_eq!(rows(&matrix.flip_horizontal()), [vec![2, 1, 0], vec![12, 11, 10]]);
+        assert_eq!(rows(&matrix.flip_vertical()), [vec![10, 11, 12], vec![0, 1, 2]]);
+        assert_eq!(rows(&matrix.flip_horizontal().flip_vertical()), rows(&matrix.rotate_cw().rotate_cw()));
+    }
+
+    #[test]
+    fn map_and_zip_with() {
+        let matrix = numbered(2, 2);
+        let strings = matrix.map(|value| value.to_string());
+        assert_eq!(strings.get(1, 1), "11");
+        let sums = matrix.zip_with(&matrix.transpose(), |a, b| a + b);
+        assert_eq!(rows(&sums), [vec![0, 11], vec![11, 22]]);
+    }
+
+    #[test]
+    fn wrap_continues_at_the_opposite_edge() {
+        let matrix = numbered(3, 2);
+        let left = [Coordinates((-1, 0))];
+        let wrapped : Vec<_> = matrix.relativ_coords_with(Coordinates((0, 1)), &left, &Boundary::Wrap).map(|c| c.0).collect();
+        assert_eq!(wrapped, [(2, 1)]);
+        assert_eq!(Coordinates((-1, 5)).wrap(3, 2).0, (2, 1));
+
+        let around : Vec<_> = matrix.around_values(Coordinates((0, 0)), &Boundary::Wrap).copied().collect();
+        assert_eq!(around, [2, 0, 1, 12, 10, 11, 12, 10, 11]);
+    }
+
+    #[test]
+    fn clip_leaves_out_positions_outside() {
+        let matrix = numbered(3, 2);
+        let coords : Vec<_> = matrix.neighbor_coords(Coordinates((0, 0))).map(|c| c.0).collect();
+        assert_eq!(coords, [(1, 0), (0, 1)]);
+        let values : Vec<_> = matrix.neighbor_values(Coordinates((2, 1)), &Boundary::Clip).copied().collect();
+        assert_eq!(values, [11, 2]);
+    }
+
+    #[test]
+    fn pad_fills_in_positions_outside() {
+        let matrix = numbered(3, 2);
+        let pad = Boundary::Pad(99);
+        let values : Vec<_> = matrix.neighbor_values(Coordinates((2, 1)), &pad).copied().collect();
+        assert_eq!(values, [11, 99, 2, 99]);
+        let around : Vec<_> = matrix.around_values(Coordinates((1, 0)), &pad).copied().collect();
+        assert_eq!(around, [0, 1, 2, 99, 99, 99, 10, 11, 12]);
+        // Padded positions have no coordinates
+        assert_eq!(matrix.relativ_coords_with(Coordinates((2, 1)), &NEIGHBORS, &pad).count(), 2);
+    }
+}
@@ -0,0 +1,100 @@
+use super::{Coordinates, Matrix, Stencil};
+
+/// A connected region found by [`Matrix::label_components`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentInfo {
+    pub label : u32,
+    /// The number of cells
+    pub size : usize,
+    /// The top left and bottom right corner of the smallest rectangle around the cells
+    pub bounds : (Coordinates, Coordinates),
+    /// The first cell in reading order
+    pub representative : Coordinates
+}
+
+impl<A> Matrix<A> {
+    /// Labels the regions of cells for which `predicate` holds, where cells are connected if one
+    /// lies in the `stencil` around the other. The labels count up from 1 in reading order of the
+    /// regions' first cells, so the region labeled `n` is described by the `n-1`th info. Cells
+    /// outside of all regions get the label 0.
+    pub fn label_components(&self, predicate : impl Fn(&A) -> bool, stencil : &Stencil) -> (Matrix<u32>, Vec<ComponentInfo>) {
+        let mut labels = Matrix::new(self.width, self.height);
+        let mut infos = vec!();
+        let mut stack = vec!();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let start = Coordinates((x, y));
+                if labels[start] != 0 || !predicate(&self[start]) {
+                    continue;
+                }
+                let label = infos.len() as u32 + 1;
+                let mut info = ComponentInfo{label: label, size: 0, bounds: (start, start), representative: start};
+                labels[start] = label;
+                stack.push(start);
+                while let Some(cell) = stack.pop() {
+                    info.size += 1;
+                    let (Coordinates((left, top)), Coordinates((right, bottom))) = info.bounds;
+                    let (cx, cy) = *cell;
+                    info.bounds = (Coordinates((left.min(cx), top.min(cy))), Coordinates((right.max(cx), bottom.max(cy))));
+                    for next in self.relativ_coords(cell, stencil.offsets()) {
+                        if labels[next] == 0 && predicate(&self[next]) {
+                            labels[next] = label;
+                            stack.push(next);
+                        }
+                    }
+                }
+                infos.push(info);
+            }
+        }
+        return (labels, infos);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells(rows : &[&str]) -> Matrix<bool> {
+        Matrix::from_fn(rows[0].len(), rows.len(), |x, y| rows[y].as_bytes()[x] == b'#')
+    }
+
+    #[test]
+    fn regions_in_reading_order() {
+        let matrix = cells(&["##..#",
+                             "#..##",
+                             "..#..",
+                             "##..."]);
+        let (labels, infos) = matrix.label_components(|cell| *cell, &Stencil::von_neumann(1));
+        let label_rows : Vec<Vec<u32>> = (0..4).map(|y| labels.row(y).to_vec()).collect();
+        assert_eq!(label_rows, [vec![1, 1, 0, 0, 2],
+                                vec![1, 0, 0, 2, 2],
+                                vec![0, 0, 3, 0, 0],
+                                vec![4, 4, 0, 0, 0]]);
+
+        let sizes : Vec<usize> = infos.iter().map(|info| info.size).collect();
+        assert_eq!(sizes, [3, 3, 1, 2]);
+        assert_eq!(infos[1], ComponentInfo{label: 2, size: 3, bounds: (Coordinates((3, 0)), Coordinates((4, 1))), representative: Coordinates((4, 0))});
+        assert_eq!(infos[3].bounds, (Coordinates((0, 3)), Coordinates((1, 3))));
+    }
+
+    #[test]
+    fn the_stencil_decides_what_touches() {
+        let matrix = cells(&["#..",
+                             ".#.",
+                             "..#"]);
+        let (_, apart) = matrix.label_components(|cell| *cell, &Stencil::von_neumann(1));
+        assert_eq!(apart.len(), 3);
+        let (labels, diagonal) = matrix.label_components(|cell| *cell, &Stencil::moore(1));
+        assert_eq!(diagonal.len(), 1);
+        assert_eq!(diagonal[0].bounds, (Coordinates((0, 0)), Coordinates((2, 2))));
+        assert_eq!((labels[Coordinates((2, 2))], labels[Coordinates((1, 0))]), (1, 0));
+    }
+
+    #[test]
+    fn nothing_to_label() {
+        let matrix = cells(&["...", "..."]);
+        let (labels, infos) = matrix.label_components(|cell| *cell, &Stencil::moore(1));
+        assert!(infos.is_empty());
+        assert!(labels.iter().all(|label| *label == 0));
+    }
+}
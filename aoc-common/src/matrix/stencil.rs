@@ -0,0 +1,110 @@
+use super::{Coordinates, RelativeCoordinates};
+
+/// The shape of a neighborhood, given by the offsets of the neighbors from the origin.
+///
+/// Neighbors are visited in the order of the offsets, the presets go row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stencil {
+    offsets: Vec<RelativeCoordinates>
+}
+
+impl Stencil {
+    /// A neighborhood of arbitrary offsets
+    pub fn new(offsets: Vec<RelativeCoordinates>) -> Stencil {
+        Stencil{offsets: offsets}
+    }
+
+    // All offsets in the square of the given radius for which `include` holds
+    fn square(radius: usize, include: impl Fn(isize, isize) -> bool) -> Stencil {
+        let radius = radius as isize;
+        let mut offsets = vec!();
+        for y in -radius..=radius {
+            for x in -radius..=radius {
+                if include(x, y) {
+                    offsets.push(Coordinates((x, y)));
+                }
+            }
+        }
+        return Stencil{offsets: offsets};
+    }
+
+    /// Everything within the given Manhattan distance except the origin itself. Radius 1 gives the
+    /// four direct neighbors.
+    pub fn von_neumann(radius: usize) -> Stencil {
+        Stencil::square(radius, |x, y| (x != 0 || y != 0) && x.unsigned_abs() + y.unsigned_abs() <= radius)
+    }
+
+    /// The square with the given radius around the origin, including the origin itself
+    pub fn moore(radius: usize) -> Stencil {
+        Stencil::square(radius, |_, _| true)
+    }
+
+    /// The square with the given radius around the origin, without the origin itself. Radius 1
+    /// gives the eight surrounding positions.
+    pub fn moore_without_center(radius: usize) -> Stencil {
+        Stencil::square(radius, |x, y| x != 0 || y != 0)
+    }
+
+    /// The positions a knight can move to in chess
+    pub fn knight() -> Stencil {
+        Stencil::square(2, |x, y| x.abs() + y.abs() == 3)
+    }
+
+    /// The six neighbors on a hexagonal grid stored in axial coordinates, i.e., with every row
+    /// shifted half a cell further to the right than the one above
+    pub fn hex() -> Stencil {
+        Stencil::square(1, |x, y| x != y)
+    }
+
+    /// The same neighborhood without the origin
+    pub fn without_center(mut self) -> Stencil {
+        self.offsets.retain(|offset| **offset != (0, 0));
+        return self;
+    }
+
+    pub fn offsets(&self) -> &[RelativeCoordinates] { &self.offsets }
+    pub fn len(&self) -> usize { self.offsets.len() }
+    pub fn is_empty(&self) -> bool { self.offsets.is_empty() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+
+    fn offsets(stencil: &Stencil) -> Vec<(isize, isize)> {
+        stencil.offsets().iter().map(|offset| **offset).collect()
+    }
+
+    #[test]
+    fn knight_moves() {
+        assert_eq!(offsets(&Stencil::knight()),
+                   [(-1, -2), (1, -2), (-2, -1), (2, -1), (-2, 1), (2, 1), (-1, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn hex_neighbors() {
+        assert_eq!(offsets(&Stencil::hex()), [(0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1)]);
+    }
+
+    #[test]
+    fn square_presets() {
+        assert_eq!(offsets(&Stencil::von_neumann(1)), [(0, -1), (-1, 0), (1, 0), (0, 1)]);
+        assert_eq!(Stencil::von_neumann(2).len(), 12);
+        assert_eq!(Stencil::moore(1).len(), 9);
+        assert_eq!(Stencil::moore_without_center(2).len(), 24);
+        assert_eq!(Stencil::moore(1).without_center(), Stencil::moore_without_center(1));
+        assert!(Stencil::new(vec!()).is_empty());
+    }
+
+    #[test]
+    fn neighbors_with_stays_within_the_matrix() {
+        let matrix = Matrix::from_fn(4, 3, |x, y| 10*y + x);
+        let knight : Vec<_> = matrix.neighbors_with(&Stencil::knight(), Coordinates((0, 0)))
+            .map(|(coords, value)| (*coords, *value)).collect();
+        assert_eq!(knight, [((2, 1), 12), ((1, 2), 21)]);
+        let custom = Stencil::new(vec![Coordinates((3, 0)), Coordinates((0, 0)), Coordinates((4, 0))]);
+        let values : Vec<_> = matrix.neighbors_with(&custom, Coordinates((0, 2))).map(|(_, value)| *value).collect();
+        assert_eq!(values, [23, 20]);
+    }
+}
@@ -0,0 +1,296 @@
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut, Range};
+use std::slice;
+
+use super::{Coordinates, CoordsIter, Matrix};
+
+/// The part of the data of a matrix with rows of length `stride` that holds the `width` x `height`
+/// region starting at (`x`, `y`). The first entry is the top left corner and the last entry is the
+/// bottom right corner of the region.
+pub(super) fn region(stride: usize, x: usize, y: usize, width: usize, height: usize) -> Range<usize> {
+    if width == 0 || height == 0 {
+        return 0..0;
+    }
+    let start = y*stride + x;
+    return start..start + (height-1)*stride + width;
+}
+
+/// A column of a matrix, see [`Matrix::column`]
+pub struct ColumnView<'a, A> {
+    data: &'a [A],
+    stride: usize
+}
+
+impl<'a, A> ColumnView<'a, A> {
+    pub(super) fn new(data: &'a [A], stride: usize) -> ColumnView<'a, A> {
+        ColumnView{data: data, stride: stride}
+    }
+
+    pub fn len(&self) -> usize { self.data.len().div_ceil(self.stride) }
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
+    pub fn get(&self, y: usize) -> Option<&'a A> { self.data.get(y*self.stride) }
+
+    pub fn iter(&self) -> StepBy<slice::Iter<'a, A>> { self.data.iter().step_by(self.stride) }
+}
+
+impl<A> Index<usize> for ColumnView<'_, A> {
+    type Output = A;
+
+    fn index(&self, y: usize) -> &A { &self.data[y*self.stride] }
+}
+
+impl<'a, A> IntoIterator for ColumnView<'a, A> {
+    type Item = &'a A;
+    type IntoIter = StepBy<slice::Iter<'a, A>>;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+/// A mutable column of a matrix, see [`Matrix::column_mut`]
+pub struct ColumnViewMut<'a, A> {
+    data: &'a mut [A],
+    stride: usize
+}
+
+impl<'a, A> ColumnViewMut<'a, A> {
+    pub(super) fn new(data: &'a mut [A], stride: usize) -> ColumnViewMut<'a, A> {
+        ColumnViewMut{data: data, stride: stride}
+    }
+
+    pub fn len(&self) -> usize { self.data.len().div_ceil(self.stride) }
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
+    pub fn get(&self, y: usize) -> Option<&A> { self.data.get(y*self.stride) }
+    pub fn get_mut(&mut self, y: usize) -> Option<&mut A> { self.data.get_mut(y*self.stride) }
+
+    pub fn iter(&self) -> StepBy<slice::Iter<'_, A>> { self.data.iter().step_by(self.stride) }
+    pub fn iter_mut(&mut self) -> StepBy<slice::IterMut<'_, A>> { self.data.iter_mut().step_by(self.stride) }
+}
+
+impl<A> Index<usize> for ColumnViewMut<'_, A> {
+    type Output = A;
+
+    fn index(&self, y: usize) -> &A { &self.data[y*self.stride] }
+}
+
+impl<A> IndexMut<usize> for ColumnViewMut<'_, A> {
+    fn index_mut(&mut self, y: usize) -> &mut A { &mut self.data[y*self.stride] }
+}
+
+impl<'a, A> IntoIterator for ColumnViewMut<'a, A> {
+    type Item = &'a mut A;
+    type IntoIter = StepBy<slice::IterMut<'a, A>>;
+
+    fn into_iter(self) -> Self::IntoIter { self.data.iter_mut().step_by(self.stride) }
+}
+
+/// The rows of a matrix view, see [`MatrixView::rows`]
+pub struct Rows<'a, A> {
+    data: &'a [A],
+    stride: usize,
+    width: usize,
+    remaining: usize
+}
+
+impl<'a, A> Iterator for Rows<'a, A> {
+    type Item = &'a [A];
+
+    fn next(&mut self) -> Option<&'a [A]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let row = &self.data[..self.width];
+        self.data = &self.data[std::cmp::min(self.stride, self.data.len())..];
+        return Some(row);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.remaining, Some(self.remaining)) }
+}
+
+impl<A> ExactSizeIterator for Rows<'_, A> {}
+
+/// A rectangular region of a matrix, see [`Matrix::sub_matrix`]. Coordinates are relative to the
+/// top left corner of the region.
+pub struct MatrixView<'a, A> {
+    data: &'a [A],
+    stride: usize,
+    width: usize,
+    height: usize
+}
+
+impl<'a, A> MatrixView<'a, A> {
+    pub(super) fn new(data: &'a [A], stride: usize, width: usize, height: usize) -> MatrixView<'a, A> {
+        MatrixView{data: data, stride: stride, width: width, height: height}
+    }
+
+    pub fn get_width(&self) -> usize { self.width }
+    pub fn get_height(&self) -> usize { self.height }
+    pub fn len(&self) -> usize { self.width * self.height }
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    fn calc_coordinates(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height);
+        y*self.stride + x
+    }
+    pub fn get(&self, x: usize, y: usize) -> &'a A { &self.data[self.calc_coordinates(x, y)] }
+
+    pub fn row(&self, y: usize) -> &'a [A] {
+        assert!(y < self.height);
+        &self.data[y*self.stride..y*self.stride + self.width]
+    }
+
+    pub fn rows(&self) -> Rows<'a, A> {
+        Rows{data: self.data, stride: self.stride, width: self.width, remaining: self.height}
+    }
+
+    pub fn column(&self, x: usize) -> ColumnView<'a, A> {
+        assert!(x < self.width);
+        ColumnView::new(&self.data[region(self.stride, x, 0, 1, self.height)], self.stride)
+    }
+
+    pub fn coords_iter(&self) -> CoordsIter {
+        CoordsIter{height: self.height, next: 0, end: self.len()}
+    }
+
+    pub fn sub_matrix(&self, x: usize, y: usize, width: usize, height: usize) -> MatrixView<'a, A> {
+        assert!(x + width <= self.width && y + height <= self.height);
+        MatrixView::new(&self.data[region(self.stride, x, y, width, height)], self.stride, width, height)
+    }
+}
+
+impl<A: Clone> MatrixView<'_, A> {
+    /// Copies the region into a matrix of its own
+    pub fn to_matrix(&self) -> Matrix<A> {
+        let data = self.rows().flat_map(|row| row.iter().cloned()).collect();
+        return Matrix{width: self.width, height: self.height, data: data};
+    }
+}
+
+impl<A> Index<Coordinates> for MatrixView<'_, A> {
+    type Output = A;
+
+    fn index(&self, coords: Coordinates) -> &A {
+        let (x, y) = *coords;
+        return self.get(x, y);
+    }
+}
+
+/// A mutable rectangular region of a matrix, see [`Matrix::sub_matrix_mut`]. Coordinates are
+/// relative to the top left corner of the region.
+pub struct MatrixViewMut<'a, A> {
+    data: &'a mut [A],
+    stride: usize,
+    width: usize,
+    height: usize
+}
+
+impl<'a, A> MatrixViewMut<'a, A> {
+    pub(super) fn new(data: &'a mut [A], stride: usize, width: usize, height: usize) -> MatrixViewMut<'a, A> {
+        MatrixViewMut{data: data, stride: stride, width: width, height: height}
+    }
+
+    pub fn get_width(&self) -> usize { self.width }
+    pub fn get_height(&self) -> usize { self.height }
+    pub fn len(&self) -> usize { self.width * self.height }
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// A read-only view of the same region
+    pub fn as_view(&self) -> MatrixView<'_, A> {
+        MatrixView::new(self.data, self.stride, self.width, self.height)
+    }
+
+    fn calc_coordinates(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height);
+        y*self.stride + x
+    }
+    pub fn get(&self, x: usize, y: usize) -> &A { &self.data[self.calc_coordinates(x, y)] }
+    pub fn get_mut(&mut self, x: usize, y: usize) -> &mut A {
+        let index = self.calc_coordinates(x, y);
+        &mut self.data[index]
+    }
+    pub fn set(&mut self, x: usize, y: usize, val: A) {
+        *self.get_mut(x, y) = val;
+    }
+
+    pub fn row(&self, y: usize) -> &[A] { self.as_view().row(y) }
+    pub fn row_mut(&mut self, y: usize) -> &mut [A] {
+        assert!(y < self.height);
+        &mut self.data[y*self.stride..y*self.stride + self.width]
+    }
+
+    pub fn column(&self, x: usize) -> ColumnView<'_, A> { self.as_view().column(x) }
+    pub fn column_mut(&mut self, x: usize) -> ColumnViewMut<'_, A> {
+        assert!(x < self.width);
+        ColumnViewMut::new(&mut self.data[region(self.stride, x, 0, 1, self.height)], self.stride)
+    }
+
+    pub fn coords_iter(&self) -> CoordsIter { self.as_view().coords_iter() }
+
+    pub fn sub_matrix_mut(&mut self, x: usize, y: usize, width: usize, height: usize) -> MatrixViewMut<'_, A> {
+        assert!(x + width <= self.width && y + height <= self.height);
+        MatrixViewMut::new(&mut self.data[region(self.stride, x, y, width, height)], self.stride, width, height)
+    }
+}
+
+impl<A> Index<Coordinates> for MatrixViewMut<'_, A> {
+    type Output = A;
+
+    fn index(&self, coords: Coordinates) -> &A {
+        let (x, y) = *coords;
+        return self.get(x, y);
+    }
+}
+
+impl<A> IndexMut<Coordinates> for MatrixViewMut<'_, A> {
+    fn index_mut(&mut self, coords: Coordinates) -> &mut A {
+        let (x, y) = *coords;
+        return self.get_mut(x, y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(width: usize, height: usize) -> Matrix<usize> {
+        Matrix::from_fn(width, height, |x, y| 10*y + x)
+    }
+
+    #[test]
+    fn sub_matrix_of_a_wide_matrix() {
+        let matrix = numbered(5, 3);
+        let view = matrix.sub_matrix(1, 1, 3, 2);
+        assert_eq!((view.get_width(), view.get_height()), (3, 2));
+        assert_eq!(*view.get(2, 1), 23);
+        assert_eq!(view.row(0), [11, 12, 13]);
+        assert_eq!(view.rows().collect::<Vec<_>>(), [[11, 12, 13], [21, 22, 23]]);
+        assert_eq!(view.column(1).iter().copied().collect::<Vec<_>>(), [12, 22]);
+        assert_eq!(view.sub_matrix(1, 0, 2, 1).row(0), [12, 13]);
+        assert_eq!(view.to_matrix().iter().copied().collect::<Vec<_>>(), [11, 12, 13, 21, 22, 23]);
+    }
+
+    #[test]
+    fn sub_matrix_of_a_tall_matrix_at_the_edge() {
+        let matrix = numbered(2, 4);
+        let view = matrix.sub_matrix(1, 2, 1, 2);
+        assert_eq!(view.column(0).iter().copied().collect::<Vec<_>>(), [21, 31]);
+        assert!(matrix.sub_matrix(2, 4, 0, 0).is_empty());
+    }
+
+    #[test]
+    fn sub_matrix_mut_writes_through() {
+        let mut matrix = numbered(4, 3);
+        {
+            let mut view = matrix.sub_matrix_mut(1, 0, 2, 3);
+            view.set(0, 0, 100);
+            view.row_mut(1)[1] = 200;
+            view.column_mut(1)[2] = 300;
+            view.sub_matrix_mut(0, 2, 1, 1)[Coordinates((0, 0))] = 400;
+        }
+        assert_eq!(matrix.row(0), [0, 100, 2, 3]);
+        assert_eq!(matrix.row(1), [10, 11, 200, 13]);
+        assert_eq!(matrix.row(2), [20, 400, 300, 23]);
+    }
+}
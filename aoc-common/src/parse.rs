@@ -0,0 +1,395 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+use crate::matrix::Matrix;
+
+/// A place in the input. Both line and column are 1-based, the column counts bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    Eof { at: Position },
+    Io { at: Position, source: io::Error },
+    NotEmpty { at: Position },
+    Inner { at: Position, source: Box<dyn Error + Send + Sync> },
+    MatrixDimensions { at: Position, expected: usize, found: usize },
+    Invalid { at: Position, message: String }
+}
+
+impl ParseError {
+    pub fn position(&self) -> Position {
+        match self {
+            ParseError::Eof { at }
+            | ParseError::Io { at, .. }
+            | ParseError::NotEmpty { at }
+            | ParseError::Inner { at, .. }
+            | ParseError::MatrixDimensions { at, .. }
+            | ParseError::Invalid { at, .. } => *at
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.position())?;
+        match self {
+            ParseError::Eof { .. } => write!(f, "unexpected end of input"),
+            ParseError::Io { source, .. } => write!(f, "{}", source),
+            ParseError::NotEmpty { .. } => write!(f, "expected empty line but got content"),
+            ParseError::Inner { source, .. } => write!(f, "{}", source),
+            ParseError::MatrixDimensions { expected, found, .. } =>
+                write!(f, "matrix row has {} entries, but the first row had {}", found, expected),
+            ParseError::Invalid { message, .. } => write!(f, "{}", message)
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Io { source, .. } => Some(source),
+            ParseError::Inner { source, .. } => Some(source.as_ref()),
+            _ => None
+        }
+    }
+}
+
+/// Hands out the input line by line and remembers where it is, so errors can point at the
+/// offending line and column.
+///
+/// A cursor either walks over lines that are already in memory, streams them from a reader, or,
+/// if created by [`split`], walks over the parts of a single line. In the latter case, every part
+/// keeps the line number and its column in the original line.
+///
+/// Lines behave like `str::split('\n')` in all cases, so input ending in a newline is followed by
+/// one empty line.
+pub struct Cursor<'a> {
+    source: Source<'a>,
+    line: usize,
+    // Byte offset of the part handed out last within its line, and its length
+    offset: usize,
+    length: usize,
+    // For cursors over the parts of a line: that line and its offset
+    base: Option<(&'a str, usize)>
+}
+
+enum Source<'a> {
+    // The lines not handed out yet, some of which may already be taken from the iterator
+    Lines{lines: Box<dyn Iterator<Item = &'a str> + 'a>, ahead: VecDeque<&'a str>},
+    Reader{reader: Box<dyn BufRead + 'a>, buffer: String, done: bool}
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(lines: impl Iterator<Item = &'a str> + 'a) -> Cursor<'a> {
+        return Cursor::from_source(Source::Lines{lines: Box::new(lines), ahead: VecDeque::new()});
+    }
+
+    /// Streams the lines from `reader`, only the current line is kept in memory
+    pub fn from_reader(reader: impl BufRead + 'a) -> Cursor<'a> {
+        return Cursor::from_source(Source::Reader{reader: Box::new(reader), buffer: String::new(), done: false});
+    }
+
+    fn from_source(source: Source<'a>) -> Cursor<'a> {
+        return Cursor{source: source, line: 0, offset: 0, length: 0, base: None};
+    }
+
+    /// Hands out the next part together with the position of its start
+    fn next_line(&mut self) -> Result<(&str, Position), ParseError> {
+        let end = self.end_position();
+        let next = match &mut self.source {
+            Source::Lines{lines, ahead} => {
+                let next = ahead.pop_front().or_else(|| lines.next()).ok_or(ParseError::Eof{at: end})?;
+                match self.base {
+                    None => {
+                        self.line += 1;
+                        self.offset = 0;
+                    },
+                    Some((base, base_offset)) => {
+                        self.offset = base_offset + distance(base, next);
+                    }
+                }
+                next
+            },
+            Source::Reader{reader, buffer, done} => {
+                if *done {
+                    return Err(ParseError::Eof{at: end});
+                }
+                buffer.clear();
+                reader.read_line(buffer).map_err(|err| ParseError::Io{at: end, source: err})?;
+                if buffer.ends_with('\n') {
+                    buffer.pop();
+                } else {
+                    *done = true;
+                }
+                self.line += 1;
+                self.offset = 0;
+                buffer.as_str()
+            }
+        };
+        self.length = next.len();
+        return Ok((next, Position{line: self.line, column: self.offset + 1}));
+    }
+
+    /// Whether all of the input is handed out. The empty line after a final newline does not count
+    /// as input left, unless the cursor walks over the parts of a line.
+    pub fn at_end(&mut self) -> bool {
+        let parts = self.base.is_some();
+        match &mut self.source {
+            Source::Lines{lines, ahead} => {
+                while ahead.len() < 2 {
+                    match lines.next() {
+                        Some(line) => ahead.push_back(line),
+                        None => { break; }
+                    }
+                }
+                return match ahead.as_slices() {
+                    ([], _) => true,
+                    ([""], []) => !parts,
+                    _ => false
+                };
+            },
+            Source::Reader{reader, done, ..} => {
+                // Without more to read, all that is left is the empty line after the final newline.
+                // Read errors are left to the next line.
+                return *done || reader.fill_buf().is_ok_and(|buffer| buffer.is_empty());
+            }
+        }
+    }
+
+    /// The position of the start of the part handed out last
+    pub fn position(&self) -> Position {
+        Position{line: self.line, column: self.offset + 1}
+    }
+
+    /// The position right after the part handed out last, i.e., where more input was expected
+    fn end_position(&self) -> Position {
+        match self.base {
+            None => Position{line: self.line + 1, column: 1},
+            Some(_) => Position{line: self.line, column: self.offset + self.length + 1}
+        }
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::Invalid{at: self.position(), message: message.into()}
+    }
+}
+
+fn distance(outer: &str, inner: &str) -> usize {
+    (inner.as_ptr() as usize).saturating_sub(outer.as_ptr() as usize)
+}
+
+/// The position of `part`, which must be a slice of the line starting at `start`
+fn position_in(start: Position, line: &str, part: &str) -> Position {
+    Position{line: start.line, column: start.column + distance(line, part)}
+}
+
+pub fn get_next_line<'c>(cursor : &'c mut Cursor) -> Result<&'c str, ParseError> {
+    cursor.next_line().map(|(line, _)| line)
+}
+
+/// Reads the next line and returns a cursor over its parts between the `seperator`s
+pub fn split<'c>(cursor : &'c mut Cursor, seperator : &'c str) -> Result<Cursor<'c>, ParseError> {
+    let (line, at) = cursor.next_line()?;
+    let mut parts = Cursor::from_source(Source::Lines{lines: Box::new(line.split(seperator)), ahead: VecDeque::new()});
+    parts.line = at.line;
+    parts.offset = at.column - 1;
+    parts.base = Some((line, at.column - 1));
+    return Ok(parts);
+}
+
+pub fn list<A : FromStr>(cursor : & mut Cursor, seperator : char) -> Result<Vec<A>, ParseError>
+    where A::Err: Into<Box<dyn Error + Send + Sync>>
+{
+    let (line, start) = cursor.next_line()?;
+    line.split(seperator)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| entry.parse::<A>().map_err(|err| ParseError::Inner{at: position_in(start, line, entry), source: err.into()}))
+        .collect()
+}
+
+pub fn characters<A, E>(cursor : & mut Cursor, map: fn(char) -> Result<A, E>) -> Result<Vec<A>, ParseError>
+    where E: Into<Box<dyn Error + Send + Sync>>
+{
+    let (line, start) = cursor.next_line()?;
+    line.char_indices()
+        .map(|(index, ch)| map(ch).map_err(|err| ParseError::Inner{at: position_in(start, line, &line[index..]), source: err.into()}))
+        .collect()
+}
+
+pub fn empty(cursor : & mut Cursor) -> Result<(), ParseError>  {
+    let (line, at) = cursor.next_line()?;
+    if !line.is_empty() { Err(ParseError::NotEmpty{at: at}) } else { Ok(()) }
+}
+
+pub fn n<Type>(cursor : & mut Cursor, count : usize, f : fn(& mut Cursor) -> Result<Type, ParseError>) -> Result<Vec<Type>, ParseError> {
+    let mut collection : Vec<Type> = Vec::with_capacity(count);
+    for _ in 0..count {
+        collection.push(f(cursor)?);
+    }
+    return Ok(collection);
+}
+
+/// Applies `f` until it fails or the input ends, the first error ends the collection. Errors
+/// reading the input are returned instead.
+pub fn all<Type>(cursor : & mut Cursor, f : fn(& mut Cursor) -> Result<Type, ParseError>) -> Result<Vec<Type>, ParseError> {
+    let mut collection : Vec<Type> = vec!();
+    while !cursor.at_end() {
+        let next = f(cursor);
+        match next {
+            Ok(new) => collection.push(new),
+            Err(err @ ParseError::Io{..}) => { return Err(err); },
+            Err(_) => { break; }
+        }
+    }
+    return Ok(collection);
+}
+
+pub fn tuple<A : FromStr>(cursor : & mut Cursor) -> Result<(A,A), ParseError>
+    where A::Err: Into<Box<dyn Error + Send + Sync>>
+{
+    let mut res = list(cursor, ',')?;
+    if res.len() != 2 {
+        return Err(cursor.error(format!("expected 2 entries but got {}", res.len())));
+    }
+    let snd = res.remove(1);
+    let fst = res.remove(0);
+    return Ok((fst, snd));
+}
+
+pub fn matrix<Type>(cursor : & mut Cursor, f : fn(& mut Cursor) -> Result<Vec<Type>, ParseError>) -> Result<Matrix<Type>, ParseError> {
+    let first_line = f(cursor)?;
+    let size = first_line.len();
+    let mut result = Matrix::new_from_row(first_line);
+    while !cursor.at_end() {
+        let mut row = f(cursor)?;
+        if row.len() != size {
+            return Err(ParseError::MatrixDimensions{at: cursor.position(), expected: size, found: row.len()});
+        }
+        result.append_row(&mut row);
+    }
+    return Ok(result);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digit(c : char) -> Result<u32, &'static str> {
+        c.to_digit(10).ok_or("Digit was no digit")
+    }
+
+    fn at(line : usize, column : usize) -> Position {
+        Position{line: line, column: column}
+    }
+
+    #[test]
+    fn lists_and_tuples() {
+        let mut cursor = Cursor::new("1 2  3\n4,5\n6,7,8".split("\n"));
+        assert_eq!(list::<u32>(&mut cursor, ' ').unwrap(), [1, 2, 3]);
+        assert_eq!(tuple::<u32>(&mut cursor).unwrap(), (4, 5));
+        let err = tuple::<u32>(&mut cursor).unwrap_err();
+        assert!(matches!(err, ParseError::Invalid{..}));
+        assert_eq!(err.to_string(), "3:1: expected 2 entries but got 3");
+    }
+
+    #[test]
+    fn blocks_of_lines() {
+        let mut cursor = Cursor::new("12\n34\n\n56".split("\n"));
+        assert_eq!(n(&mut cursor, 2, |cursor| characters(cursor, digit)).unwrap(), [[1, 2], [3, 4]]);
+        assert!(empty(&mut cursor).is_ok());
+        assert_eq!(empty(&mut cursor).unwrap_err().position(), at(4, 1));
+        assert!(all(&mut cursor, |cursor| list::<u32>(cursor, ' ')).unwrap().is_empty());
+    }
+
+    #[test]
+    fn matrices() {
+        let matrix = matrix(&mut Cursor::new("123\n456".split("\n")), |cursor| characters(cursor, digit)).unwrap();
+        assert_eq!((matrix.get_width(), matrix.get_height()), (3, 2));
+        assert_eq!(*matrix.get(2, 1), 6);
+        let Err(err) = super::matrix(&mut Cursor::new("12\n34\n5".split("\n")), |cursor| characters(cursor, digit)) else {
+            panic!("rows of different lengths are no matrix");
+        };
+        assert!(matches!(err, ParseError::MatrixDimensions{expected: 2, found: 1, ..}));
+        assert_eq!(err.position(), at(3, 1));
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_entry() {
+        let mut cursor = Cursor::new("1 2\n3 x4 5".split("\n"));
+        list::<u32>(&mut cursor, ' ').unwrap();
+        let err = list::<u32>(&mut cursor, ' ').unwrap_err();
+        assert!(matches!(err, ParseError::Inner{..}));
+        assert_eq!(err.to_string(), "2:3: invalid digit found in string");
+        assert_eq!(get_next_line(&mut cursor).unwrap_err().position(), at(3, 1));
+
+        let mut cursor = Cursor::new("1234\n12a4".split("\n"));
+        characters(&mut cursor, digit).unwrap();
+        assert_eq!(characters(&mut cursor, digit).unwrap_err().to_string(), "2:3: Digit was no digit");
+    }
+
+    #[test]
+    fn split_keeps_the_columns_of_the_line() {
+        let mut cursor = Cursor::new("skip\n1,2 -> x,4".split("\n"));
+        get_next_line(&mut cursor).unwrap();
+        let mut parts = split(&mut cursor, " -> ").unwrap();
+        assert_eq!(tuple::<u32>(&mut parts).unwrap(), (1, 2));
+        assert_eq!(tuple::<u32>(&mut parts).unwrap_err().position(), at(2, 8));
+        assert_eq!(get_next_line(&mut parts).unwrap_err().position(), at(2, 11));
+    }
+
+    // The same text as lines in memory and streamed from a reader
+    fn both_sources(text : &str) -> [Cursor<'_>; 2] {
+        [Cursor::new(text.split("\n")), Cursor::from_reader(text.as_bytes())]
+    }
+
+    #[test]
+    fn a_final_newline_is_no_record() {
+        for text in ["12\n34", "12\n34\n"] {
+            for mut cursor in both_sources(text) {
+                let matrix = matrix(&mut cursor, |cursor| characters(cursor, digit)).unwrap();
+                assert_eq!((matrix.get_width(), matrix.get_height()), (2, 2), "{:?}", text);
+                assert!(cursor.at_end());
+            }
+            for mut cursor in both_sources(text) {
+                assert_eq!(all(&mut cursor, |cursor| list::<u32>(cursor, ' ')).unwrap().len(), 2, "{:?}", text);
+            }
+        }
+    }
+
+    #[test]
+    fn empty_lines_before_the_end_are_input() {
+        for mut cursor in both_sources("12\n\n") {
+            assert!(!cursor.at_end());
+            let Err(err) = matrix(&mut cursor, |cursor| characters(cursor, digit)) else {
+                panic!("an empty row is no matrix row");
+            };
+            assert_eq!(err.position(), at(2, 1));
+        }
+        for mut cursor in both_sources("\n") {
+            assert!(!cursor.at_end());
+            assert_eq!(get_next_line(&mut cursor).unwrap(), "");
+            assert!(cursor.at_end());
+        }
+        for mut cursor in both_sources("") {
+            assert!(cursor.at_end());
+        }
+        // The parts of a line end only after the last one
+        let mut cursor = Cursor::new(["1,"].into_iter());
+        let mut parts = split(&mut cursor, ",").unwrap();
+        get_next_line(&mut parts).unwrap();
+        assert!(!parts.at_end());
+    }
+}
@@ -0,0 +1,85 @@
+use std::env;
+use std::fmt::Display;
+use std::process;
+use std::time::Duration;
+
+use crate::input;
+use crate::parse::Cursor;
+use crate::solution::Solution;
+
+/// Prints a single answer, optionally with the time it took. Answers which span multiple lines,
+/// like drawings, start on a line of their own.
+pub fn answer(name : &str, answer : &impl Display, duration : Option<Duration>) {
+    println!("{}", format(name, answer, duration));
+}
+
+// The line, or lines, `answer` prints
+fn format(name : &str, answer : &impl Display, duration : Option<Duration>) -> String {
+    let answer = answer.to_string();
+    let duration = match duration {
+        Some(duration) => format!(" ({:?})", duration),
+        None => String::new()
+    };
+    if answer.contains('\n') {
+        return format!("{}:{}\n{}", name, duration, answer);
+    }
+    return format!("{}: {}{}", name, answer, duration);
+}
+
+/// The main function of a door: solves the input given as the only argument and prints both
+/// answers. The input may be `-` for stdin or a gzipped file, see [`input::open`]. Errors go to
+/// stderr and end the process with exit code 1, like in the `aoc` runner.
+pub fn main<S : Solution>() {
+    let args: Vec<String> = env::args().collect();
+    let Some(filename) = args.get(1) else {
+        eprintln!("Usage: {} <input file|->", args[0]);
+        process::exit(2);
+    };
+
+    let reader = match input::open(filename) {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("{}: {}", filename, err);
+            process::exit(1);
+        }
+    };
+    let mut cursor = Cursor::from_reader(reader);
+    let input = match S::parse(& mut cursor) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}:{}", filename, err);
+            process::exit(1);
+        }
+    };
+    match S::part1(&input) {
+        Ok(part1) => answer("Part 1", &part1, None),
+        Err(err) => {
+            eprintln!("Part 1: {}", err);
+            process::exit(1);
+        }
+    }
+    match S::part2(&input) {
+        Ok(part2) => answer("Part 2", &part2, None),
+        Err(err) => {
+            eprintln!("Part 2: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_line_answers() {
+        assert_eq!(format("Part 1", &42, None), "Part 1: 42");
+        assert_eq!(format("Part 2", &"abc", Some(Duration::from_millis(3))), "Part 2: abc (3ms)");
+    }
+
+    #[test]
+    fn drawings_start_on_their_own_line() {
+        assert_eq!(format("Part 2", &"#.\n.#", None), "Part 2:\n#.\n.#");
+        assert_eq!(format("Part 2", &"#.\n.#", Some(Duration::from_secs(1))), "Part 2: (1s)\n#.\n.#");
+    }
+}
@@ -0,0 +1,120 @@
+use std::io::{self, Write};
+
+use crate::matrix::Matrix;
+
+/// A 24 bit color
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// Maps `value` between 0 and 1 onto a gradient from black over red and yellow to white. Values
+/// outside of that range are clamped.
+pub fn heat(value : f64) -> Rgb {
+    let value = if value.is_nan() { 0.0 } else { value.clamp(0.0, 1.0) };
+    let channel = |start : f64| ((value * 3.0 - start).clamp(0.0, 1.0) * 255.0).round() as u8;
+    return Rgb(channel(0.0), channel(1.0), channel(2.0));
+}
+
+/// Joins the characters of every row into one line
+pub fn text(characters : &Matrix<char>) -> String {
+    let rows : Vec<String> = (0..characters.get_height())
+        .map(|y| characters.row(y).iter().collect())
+        .collect();
+    return rows.join("\n");
+}
+
+/// Draws every entry as two blanks in its color using ANSI truecolor escapes, one row per line
+pub fn ansi(image : &Matrix<Rgb>) -> String {
+    let mut out = String::new();
+    for y in 0..image.get_height() {
+        for Rgb(r, g, b) in image.row(y) {
+            out.push_str(&format!("\x1b[48;2;{};{};{}m  ", r, g, b));
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    return out;
+}
+
+/// Encodes the image as PNG, every entry becomes a square of `scale` by `scale` pixels
+pub fn png(image : &Matrix<Rgb>, scale : usize, out : impl Write) -> io::Result<()> {
+    let width = image.get_width() * scale;
+    let height = image.get_height() * scale;
+    let (Ok(png_width), Ok(png_height)) = (u32::try_from(width), u32::try_from(height)) else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "image too large for PNG"));
+    };
+
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for y in 0..image.get_height() {
+        let mut row = Vec::with_capacity(width * 3);
+        for Rgb(r, g, b) in image.row(y) {
+            for _ in 0..scale {
+                row.extend_from_slice(&[*r, *g, *b]);
+            }
+        }
+        for _ in 0..scale {
+            pixels.extend_from_slice(&row);
+        }
+    }
+
+    let mut encoder = png::Encoder::new(out, png_width, png_height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer.write_image_data(&pixels).map_err(io::Error::other)?;
+    return writer.finish().map_err(io::Error::other);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small() -> Matrix<Rgb> {
+        let mut image = Matrix::new_from_row(vec!(Rgb(0, 0, 0), Rgb(255, 0, 0)));
+        image.append_row(&mut vec!(Rgb(255, 255, 0), Rgb(255, 255, 255)));
+        return image;
+    }
+
+    #[test]
+    fn heat_runs_from_black_to_white() {
+        assert_eq!(heat(0.0), Rgb(0, 0, 0));
+        assert_eq!(heat(1.0 / 3.0), Rgb(255, 0, 0));
+        assert_eq!(heat(0.5), Rgb(255, 128, 0));
+        assert_eq!(heat(1.0), Rgb(255, 255, 255));
+        assert_eq!(heat(-2.0), heat(0.0));
+        assert_eq!(heat(7.0), heat(1.0));
+        assert_eq!(heat(f64::NAN), heat(0.0));
+    }
+
+    #[test]
+    fn text_has_no_final_newline() {
+        let mut characters = Matrix::new_from_row(vec!('#', '.'));
+        characters.append_row(&mut vec!('.', '#'));
+        assert_eq!(text(&characters), "#.\n.#");
+    }
+
+    #[test]
+    fn ansi_draws_one_line_per_row() {
+        let expected = "\x1b[48;2;0;0;0m  \x1b[48;2;255;0;0m  \x1b[0m\n\
+                        \x1b[48;2;255;255;0m  \x1b[48;2;255;255;255m  \x1b[0m\n";
+        assert_eq!(ansi(&small()), expected);
+    }
+
+    #[test]
+    fn png_scales_every_entry() {
+        let mut encoded = Vec::new();
+        png(&small(), 2, &mut encoded).unwrap();
+
+        let mut reader = ::png::Decoder::new(encoded.as_slice()).read_info().unwrap();
+        let mut pixels = vec!(0; reader.output_buffer_size());
+        let frame = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((frame.width, frame.height), (4, 4));
+        assert_eq!(frame.color_type, ::png::ColorType::Rgb);
+        let pixel = |x : usize, y : usize| {
+            let at = (y * 4 + x) * 3;
+            Rgb(pixels[at], pixels[at + 1], pixels[at + 2])
+        };
+        assert_eq!(pixel(1, 1), Rgb(0, 0, 0));
+        assert_eq!(pixel(2, 0), Rgb(255, 0, 0));
+        assert_eq!(pixel(1, 3), Rgb(255, 255, 0));
+        assert_eq!(pixel(3, 2), Rgb(255, 255, 255));
+    }
+}
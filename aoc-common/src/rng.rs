@@ -0,0 +1,45 @@
+/// A small pseudo random number generator (SplitMix64). It is not suitable for anything but
+/// generating puzzle inputs, but the same seed yields the same numbers on every platform.
+pub struct Rng {
+    state : u64
+}
+
+impl Rng {
+    pub fn new(seed : u64) -> Rng {
+        Rng{state: seed}
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        return z ^ (z >> 31);
+    }
+
+    /// A number in `0..bound`
+    pub fn below(&mut self, bound : usize) -> usize {
+        assert!(bound > 0, "Cannot pick from an empty range");
+        return (self.next_u64() % bound as u64) as usize;
+    }
+
+    /// A number in `low..=high`
+    pub fn between(&mut self, low : usize, high : usize) -> usize {
+        return low + self.below(high - low + 1);
+    }
+
+    /// True with a chance of one in `n`
+    pub fn one_in(&mut self, n : usize) -> bool {
+        return self.below(n) == 0;
+    }
+
+    pub fn choose<'a, T>(&mut self, items : &'a [T]) -> &'a T {
+        return &items[self.below(items.len())];
+    }
+
+    pub fn shuffle<T>(&mut self, items : &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
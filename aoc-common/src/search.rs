@@ -0,0 +1,271 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+mod grid;
+mod named;
+pub use grid::MatrixGraph;
+pub use named::{IdMap, NamedGraph};
+
+/// Anything that can be searched: nodes, and for every node the nodes one step away
+pub trait Graph {
+    type Node : Copy + Eq + Hash;
+
+    /// The nodes one step away from `node`, together with the cost of that step
+    fn successors(&self, node : Self::Node) -> impl Iterator<Item = (Self::Node, u64)>;
+}
+
+/// The nodes a search reached, with their cost from the start and the node they were reached from
+pub struct Paths<N> {
+    reached : HashMap<N, (u64, Option<N>)>,
+    // The nodes in the order they were reached, or for Dijkstra settled
+    order : Vec<N>
+}
+
+impl<N : Copy + Eq + Hash> Paths<N> {
+    fn new() -> Paths<N> {
+        Paths{reached: HashMap::new(), order: vec!()}
+    }
+
+    // Reaching a node again, through a cheaper path, only updates its cost and parent
+    fn reach(&mut self, node : N, cost : u64, parent : Option<N>) {
+        if self.reached.insert(node, (cost, parent)).is_none() {
+            self.order.push(node);
+        }
+    }
+
+    pub fn len(&self) -> usize { self.order.len() }
+    pub fn is_empty(&self) -> bool { self.order.is_empty() }
+    pub fn contains(&self, node : N) -> bool { self.reached.contains_key(&node) }
+
+    /// The cost of the path to `node`, for breadth first searches its number of steps
+    pub fn cost(&self, node : N) -> Option<u64> {
+        self.reached.get(&node).map(|(cost, _)| *cost)
+    }
+
+    /// The nodes from the start to `node`, both included
+    pub fn path_to(&self, node : N) -> Option<Vec<N>> {
+        let mut path = vec![node];
+        let mut current = self.reached.get(&node)?;
+        while let Some(parent) = current.1 {
+            path.push(parent);
+            current = &self.reached[&parent];
+        }
+        path.reverse();
+        return Some(path);
+    }
+
+    /// The reached nodes in the order they were reached
+    pub fn nodes(&self) -> std::slice::Iter<'_, N> {
+        self.order.iter()
+    }
+}
+
+/// Visits the nodes reachable from `start` in order of their number of steps, ignoring costs
+pub fn bfs<G : Graph>(graph : &G, start : G::Node) -> Paths<G::Node> {
+    let mut paths = Paths::new();
+    paths.reach(start, 0, None);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let steps = paths.reached[&node].0;
+        for (next, _) in graph.successors(node) {
+            if !paths.contains(next) {
+                paths.reach(next, steps + 1, Some(node));
+                queue.push_back(next);
+            }
+        }
+    }
+    return paths;
+}
+
+/// The nodes reachable from `start` in depth first preorder. Successors are visited in the order
+/// the graph lists them.
+pub fn dfs<G : Graph>(graph : &G, start : G::Node) -> Vec<G::Node> {
+    let mut visited = HashSet::new();
+    let mut order = vec!();
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+        order.push(node);
+        let successors : Vec<G::Node> = graph.successors(node).map(|(next, _)| next).collect();
+        stack.extend(successors.into_iter().rev().filter(|next| !visited.contains(next)));
+    }
+    return order;
+}
+
+/// The cheapest paths from `start` to every reachable node
+pub fn dijkstra<G : Graph>(graph : &G, start : G::Node) -> Paths<G::Node> {
+    let mut paths = Paths::new();
+    search(graph, start, |_| 0, |node| { paths.reach(node.0, node.1, node.2); false });
+    return paths;
+}
+
+/// The cost of the cheapest path from `start` to `goal` and the path itself. `heuristic` must
+/// never overestimate the remaining cost to the goal, or the path may not be the cheapest. Nodes
+/// are expanded again when a cheaper path to them turns up, which only happens if the heuristic
+/// is not also consistent.
+pub fn astar<G : Graph>(graph : &G, start : G::Node, goal : G::Node, heuristic : impl Fn(G::Node) -> u64) -> Option<(u64, Vec<G::Node>)> {
+    let mut paths = Paths::new();
+    let mut found = false;
+    search(graph, start, heuristic, |(node, cost, parent)| {
+        paths.reach(node, cost, parent);
+        found = node == goal;
+        found
+    });
+    if !found {
+        return None;
+    }
+    return Some((paths.cost(goal).unwrap(), paths.path_to(goal).unwrap()));
+}
+
+// A* without a goal, it calls `settle` with every node, its cost and its parent when the node is
+// expanded and stops once `settle` returns true. A node is expanded again for every cheaper cost
+// found later, so only the last call for a node holds its final cost. With a heuristic of 0, as
+// for Dijkstra, every node is expanded exactly once.
+fn search<G : Graph>(graph : &G, start : G::Node, heuristic : impl Fn(G::Node) -> u64, mut settle : impl FnMut((G::Node, u64, Option<G::Node>)) -> bool) {
+    // Best known cost of every node seen so far
+    let mut best : HashMap<G::Node, u64> = HashMap::new();
+    // The heap only holds indices into `candidates`, so nodes need no ordering
+    let mut candidates = vec!((start, 0, None));
+    let mut heap = BinaryHeap::from([Reverse((heuristic(start), 0))]);
+    best.insert(start, 0);
+    while let Some(Reverse((_, index))) = heap.pop() {
+        let (node, cost, parent) = candidates[index];
+        // A cheaper path to the node turned up since this candidate was pushed
+        if best[&node] < cost {
+            continue;
+        }
+        if settle((node, cost, parent)) {
+            return;
+        }
+        for (next, step) in graph.successors(node) {
+            let next_cost = cost + step;
+            match best.entry(next) {
+                Entry::Occupied(mut known) => {
+                    if *known.get() <= next_cost {
+                        continue;
+                    }
+                    known.insert(next_cost);
+                },
+                Entry::Vacant(new) => { new.insert(next_cost); }
+            }
+            heap.push(Reverse((next_cost + heuristic(next), candidates.len())));
+            candidates.push((next, next_cost, Some(node)));
+        }
+    }
+}
+
+/// Splits `nodes` into groups that are connected to each other. Edges are expected to go both
+/// ways. The groups come in the order of their first node in `nodes`, and hold the nodes reached
+/// from there, including ones not listed in `nodes`.
+pub fn components<G : Graph>(graph : &G, nodes : impl IntoIterator<Item = G::Node>) -> Vec<Vec<G::Node>> {
+    let mut seen = HashSet::new();
+    let mut components = vec!();
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component : Vec<G::Node> = bfs(graph, node).nodes().copied().collect();
+        seen.extend(component.iter().copied());
+        components.push(component);
+    }
+    return components;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::{Coordinates, Matrix, Stencil};
+
+    // start -1- a -1- b, start -5- b, b -1- goal, and c on its own
+    fn example() -> NamedGraph {
+        let mut graph = NamedGraph::new();
+        graph.connect("start", "a", 1);
+        graph.connect("a", "b", 1);
+        graph.connect("start", "b", 5);
+        graph.connect("b", "goal", 1);
+        graph.add_node("c");
+        return graph;
+    }
+
+    fn names<'g>(graph : &'g NamedGraph, nodes : &[usize]) -> Vec<&'g str> {
+        nodes.iter().map(|node| graph.name(*node)).collect()
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let graph = example();
+        let paths = bfs(&graph, graph.id("start").unwrap());
+        assert_eq!(paths.len(), 4);
+        assert_eq!(paths.cost(graph.id("b").unwrap()), Some(1));
+        assert_eq!(paths.cost(graph.id("goal").unwrap()), Some(2));
+        assert!(!paths.contains(graph.id("c").unwrap()));
+        assert_eq!(names(&graph, &paths.path_to(graph.id("goal").unwrap()).unwrap()), ["start", "b", "goal"]);
+    }
+
+    #[test]
+    fn dfs_visits_successors_in_order() {
+        let graph = example();
+        assert_eq!(names(&graph, &dfs(&graph, graph.id("start").unwrap())), ["start", "a", "b", "goal"]);
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_paths() {
+        let graph = example();
+        let paths = dijkstra(&graph, graph.id("start").unwrap());
+        assert_eq!(paths.cost(graph.id("b").unwrap()), Some(2));
+        assert_eq!(paths.cost(graph.id("goal").unwrap()), Some(3));
+        assert_eq!(names(&graph, &paths.path_to(graph.id("goal").unwrap()).unwrap()), ["start", "a", "b", "goal"]);
+        let settled : Vec<usize> = paths.nodes().copied().collect();
+        assert_eq!(names(&graph, &settled), ["start", "a", "b", "goal"]);
+        assert_eq!(paths.path_to(graph.id("c").unwrap()), None);
+    }
+
+    #[test]
+    fn astar_on_a_matrix() {
+        // Walls are 9, every step costs the value of the cell stepped onto
+        let rows = [[1, 1, 1, 1],
+                    [9, 9, 9, 1],
+                    [1, 1, 1, 1]];
+        let matrix = Matrix::from_fn(4, 3, |x, y| rows[y][x]);
+        let graph = MatrixGraph::new(&matrix, Stencil::von_neumann(1), |_, to : &u64| (*to < 9).then_some(*to));
+        let goal = Coordinates((0, 2));
+        let distance = |Coordinates((x, y)) : Coordinates| (x.abs_diff(0) + y.abs_diff(2)) as u64;
+
+        let (cost, path) = astar(&graph, Coordinates((0, 0)), goal, distance).unwrap();
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), 9);
+        assert_eq!(path[4], Coordinates((3, 1)));
+        assert_eq!(dijkstra(&graph, Coordinates((0, 0))).cost(goal), Some(8));
+        assert_eq!(astar(&graph, Coordinates((0, 0)), Coordinates((1, 1)), |_| 0), None);
+    }
+
+    #[test]
+    fn components_in_order_of_their_first_node() {
+        let graph = example();
+        let groups = components(&graph, (0..graph.len()).rev());
+        let groups : Vec<Vec<&str>> = groups.iter().map(|group| names(&graph, group)).collect();
+        assert_eq!(groups, [vec!["c"], vec!["goal", "b", "a", "start"]]);
+    }
+
+    #[test]
+    fn astar_reopens_nodes_for_inconsistent_heuristics() {
+        let mut graph = NamedGraph::new();
+        graph.add_edge("start", "a", 1);
+        graph.add_edge("start", "b", 1);
+        graph.add_edge("a", "c", 1);
+        graph.add_edge("b", "c", 2);
+        graph.add_edge("c", "goal", 3);
+        // Admissible, but a looks worse than it is until c was already expanded through b
+        let a = graph.id("a").unwrap();
+        let heuristic = |node| if node == a { 4 } else { 0 };
+
+        let (cost, path) = astar(&graph, graph.id("start").unwrap(), graph.id("goal").unwrap(), heuristic).unwrap();
+        let path : Vec<&str> = path.into_iter().map(|node| graph.name(node)).collect();
+        assert_eq!(cost, 5);
+        assert_eq!(path, ["start", "a", "c", "goal"]);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::matrix::{Coordinates, Matrix, Stencil};
+
+use super::Graph;
+
+/// The cells of a matrix as a graph. Every cell is connected to its neighbors in the shape of a
+/// stencil, `cost` decides the cost of a step from one value to another, or forbids it with
+/// `None`.
+pub struct MatrixGraph<'m, A, F> {
+    matrix : &'m Matrix<A>,
+    stencil : Stencil,
+    cost : F
+}
+
+impl<'m, A, F : Fn(&A, &A) -> Option<u64>> MatrixGraph<'m, A, F> {
+    pub fn new(matrix : &'m Matrix<A>, stencil : Stencil, cost : F) -> MatrixGraph<'m, A, F> {
+        MatrixGraph{matrix: matrix, stencil: stencil, cost: cost}
+    }
+}
+
+impl<'m, A, F : Fn(&A, &A) -> Option<u64>> Graph for MatrixGraph<'m, A, F> {
+    type Node = Coordinates;
+
+    fn successors(&self, node : Coordinates) -> impl Iterator<Item = (Coordinates, u64)> {
+        let from = &self.matrix[node];
+        self.matrix.relativ_coords(node, self.stencil.offsets())
+            .filter_map(move |next| (self.cost)(from, &self.matrix[next]).map(|cost| (next, cost)))
+    }
+}
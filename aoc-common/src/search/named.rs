@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use super::Graph;
+
+/// Hands out consecutive ids for names, starting at 0
+#[derive(Debug, Default)]
+pub struct IdMap {
+    ids : HashMap<String, usize>,
+    names : Vec<String>
+}
+
+impl IdMap {
+    pub fn new() -> IdMap { IdMap{ids: HashMap::new(), names: vec!()} }
+
+    /// The id of `name`, which gets the next free one if it is new
+    pub fn put(&mut self, name : &str) -> usize {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = self.names.len();
+        self.ids.insert(String::from(name), id);
+        self.names.push(String::from(name));
+        return id;
+    }
+
+    pub fn get(&self, name : &str) -> Option<usize> { self.ids.get(name).copied() }
+    pub fn name(&self, id : usize) -> &str { &self.names[id] }
+
+    pub fn len(&self) -> usize { self.names.len() }
+    pub fn is_empty(&self) -> bool { self.names.is_empty() }
+}
+
+/// A graph whose nodes have names. Nodes are identified by their id in an [`IdMap`].
+#[derive(Debug, Default)]
+pub struct NamedGraph {
+    ids : IdMap,
+    // The successors of every node with the cost to reach them
+    edges : Vec<Vec<(usize, u64)>>
+}
+
+impl NamedGraph {
+    pub fn new() -> NamedGraph { NamedGraph{ids: IdMap::new(), edges: vec!()} }
+
+    /// The id of the node called `name`, which is added if it is new
+    pub fn add_node(&mut self, name : &str) -> usize {
+        let id = self.ids.put(name);
+        if id == self.edges.len() {
+            self.edges.push(vec!());
+        }
+        return id;
+    }
+
+    /// Adds an edge from `from` to `to` only
+    pub fn add_edge(&mut self, from : &str, to : &str, cost : u64) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push((to, cost));
+    }
+
+    /// Adds edges in both directions between `a` and `b`
+    pub fn connect(&mut self, a : &str, b : &str, cost : u64) {
+        self.add_edge(a, b, cost);
+        self.add_edge(b, a, cost);
+    }
+
+    pub fn ids(&self) -> &IdMap { &self.ids }
+    pub fn id(&self, name : &str) -> Option<usize> { self.ids.get(name) }
+    pub fn name(&self, id : usize) -> &str { self.ids.name(id) }
+
+    /// The successors of the node `id` with the cost to reach them
+    pub fn edges(&self, id : usize) -> &[(usize, u64)] { &self.edges[id] }
+
+    pub fn len(&self) -> usize { self.edges.len() }
+    pub fn is_empty(&self) -> bool { self.edges.is_empty() }
+}
+
+impl Graph for NamedGraph {
+    type Node = usize;
+
+    fn successors(&self, node : usize) -> impl Iterator<Item = (usize, u64)> {
+        self.edges[node].iter().copied()
+    }
+}
@@ -0,0 +1,26 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::matrix::Matrix;
+use crate::parse::{Cursor, ParseError};
+use crate::render::Rgb;
+
+/// An answer to one part, or why the input has none.
+pub type Answer<T> = Result<T, Box<dyn Error>>;
+
+/// A puzzle of one day: how to read its input and how to answer both of its parts.
+///
+/// Parsing happens once, both parts then work on the same input, so they take it by reference.
+pub trait Solution {
+    type Input;
+    type Answer1 : Display;
+    type Answer2 : Display;
+
+    fn parse(cursor : & mut Cursor) -> Result<Self::Input, ParseError>;
+    fn part1(input : &Self::Input) -> Answer<Self::Answer1>;
+    fn part2(input : &Self::Input) -> Answer<Self::Answer2>;
+
+    /// A picture of the input, or of a state while solving it, for debugging. Doors without one
+    /// keep the default.
+    fn render(_input : &Self::Input) -> Option<Matrix<Rgb>> { None }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use crate::grid::Grid;
+use crate::matrix::{Coordinates, Matrix, Stencil};
+
+/// A grid without bounds which only stores the entries that have been set. All others hold the
+/// default value. Coordinates may be negative.
+pub struct SparseGrid<A> {
+    entries : HashMap<Coordinates<i64>, A>,
+    default : A,
+    // Top left and bottom right corner of the entries that have been set
+    bounds : Option<(Coordinates<i64>, Coordinates<i64>)>
+}
+
+impl<A> SparseGrid<A> {
+    pub fn new(default : A) -> SparseGrid<A> {
+        SparseGrid{entries: HashMap::new(), default: default, bounds: None}
+    }
+
+    pub fn get(&self, x : i64, y : i64) -> &A {
+        self.entries.get(&Coordinates((x, y))).unwrap_or(&self.default)
+    }
+
+    pub fn set(&mut self, x : i64, y : i64, value : A) {
+        self.bounds = Some(match self.bounds {
+            None => (Coordinates((x, y)), Coordinates((x, y))),
+            Some((Coordinates((left, top)), Coordinates((right, bottom)))) =>
+                (Coordinates((left.min(x), top.min(y))), Coordinates((right.max(x), bottom.max(y))))
+        });
+        self.entries.insert(Coordinates((x, y)), value);
+    }
+
+    /// The number of entries that have been set
+    pub fn len(&self) -> usize { self.entries.len() }
+    pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+    pub fn get_default(&self) -> &A { &self.default }
+
+    /// The entries that have been set, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinates<i64>, &A)> {
+        self.entries.iter().map(|(coords, value)| (*coords, value))
+    }
+
+    pub fn bounds(&self) -> Option<(Coordinates<i64>, Coordinates<i64>)> { self.bounds }
+}
+
+impl<A : Clone> SparseGrid<A> {
+    /// The entry at (`x`, `y`), which is set to the default value first if it has not been set yet
+    pub fn get_mut(&mut self, x : i64, y : i64) -> &mut A {
+        if !self.entries.contains_key(&Coordinates((x, y))) {
+            self.set(x, y, self.default.clone());
+        }
+        return self.entries.get_mut(&Coordinates((x, y))).unwrap();
+    }
+}
+
+impl<A> Grid for SparseGrid<A> {
+    type Value = A;
+    type Position = Coordinates<i64>;
+
+    fn get(&self, position : Coordinates<i64>) -> Option<&A> {
+        let (x, y) = *position;
+        return Some(SparseGrid::get(self, x, y));
+    }
+
+    fn set(&mut self, position : Coordinates<i64>, value : A) {
+        let (x, y) = *position;
+        SparseGrid::set(self, x, y, value);
+    }
+
+    fn neighbors<'a>(&self, stencil : &'a Stencil, position : Coordinates<i64>) -> impl Iterator<Item = Coordinates<i64>> + 'a {
+        let (x, y) = *position;
+        // Neighbors beyond the range of i64 are left out
+        stencil.offsets().iter().filter_map(move |offset| {
+            let (dx, dy) = **offset;
+            Some(Coordinates((x.checked_add(dx as i64)?, y.checked_add(dy as i64)?)))
+        })
+    }
+
+    fn bounds(&self) -> Option<(Coordinates<i64>, Coordinates<i64>)> { self.bounds }
+
+    fn rasterize<B>(&self, mut f : impl FnMut(&A) -> B) -> Option<Matrix<B>> {
+        let Some((Coordinates((left, top)), Coordinates((right, bottom)))) = self.bounds else {
+            return Some(Matrix::from_fn(0, 0, |_, _| unreachable!()));
+        };
+        let extent = |low : i64, high : i64| usize::try_from(high.checked_sub(low)?).ok()?.checked_add(1);
+        let (width, height) = (extent(left, right)?, extent(top, bottom)?);
+        width.checked_mul(height)?;
+        return Some(Matrix::from_fn(width, height, |x, y| f(SparseGrid::get(self, left + x as i64, top + y as i64))));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::Rgb;
+
+    #[test]
+    fn bounds_grow_with_every_entry() {
+        let mut grid = SparseGrid::new(false);
+        assert_eq!(SparseGrid::bounds(&grid), None);
+        grid.set(2, 1, true);
+        assert_eq!(SparseGrid::bounds(&grid), Some((Coordinates((2, 1)), Coordinates((2, 1)))));
+        grid.set(-3, 4, true);
+        grid.set(0, -1_000_000, false);
+        assert_eq!(SparseGrid::bounds(&grid), Some((Coordinates((-3, -1_000_000)), Coordinates((2, 4)))));
+        assert_eq!(grid.len(), 3);
+    }
+
+    #[test]
+    fn entries_default_until_set() {
+        let mut grid = SparseGrid::new(7u32);
+        assert_eq!(*SparseGrid::get(&grid, -5, 5), 7);
+        *grid.get_mut(-5, 5) += 1;
+        assert_eq!(*SparseGrid::get(&grid, -5, 5), 8);
+        assert_eq!(Grid::get(&grid, Coordinates((i64::MAX, i64::MIN))), Some(&7));
+        assert_eq!(SparseGrid::bounds(&grid), Some((Coordinates((-5, 5)), Coordinates((-5, 5)))));
+    }
+
+    #[test]
+    fn renders_the_area_within_the_bounds() {
+        let mut grid = SparseGrid::new(false);
+        assert_eq!(grid.render().as_deref(), Some(""));
+        grid.set(-1, -1, true);
+        grid.set(1, 0, true);
+        assert_eq!(grid.render().as_deref(), Some("#..\n..#"));
+        let neighbors : Vec<_> = grid.neighbors(&Stencil::von_neumann(1), Coordinates((-1, -1))).collect();
+        assert_eq!(neighbors, [Coordinates((-1, -2)), Coordinates((-2, -1)), Coordinates((0, -1)), Coordinates((-1, 0))]);
+    }
+
+    #[test]
+    fn neighbors_beyond_i64_are_left_out() {
+        let grid : SparseGrid<bool> = SparseGrid::new(false);
+        let neighbors : Vec<_> = grid.neighbors(&Stencil::von_neumann(1), Coordinates((i64::MAX, i64::MIN))).collect();
+        assert_eq!(neighbors, [Coordinates((i64::MAX - 1, i64::MIN)), Coordinates((i64::MAX, i64::MIN + 1))]);
+    }
+
+    #[test]
+    fn bounds_too_far_apart_cannot_be_rasterized() {
+        let mut grid = SparseGrid::new(false);
+        grid.set(i64::MIN, 0, true);
+        grid.set(i64::MAX, 0, true);
+        assert!(grid.rasterize(|dot| *dot).is_none());
+        assert!(grid.write_png(Vec::new(), 1, |_| Rgb(0, 0, 0)).is_err());
+    }
+}
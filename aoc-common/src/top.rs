@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+
+/// Decides which of two values ranks higher, see [`TopK`]
+pub trait Rank<T> {
+    /// `Greater` if `a` ranks higher than `b`
+    fn compare(&self, a : &T, b : &T) -> Ordering;
+}
+
+/// Ranks larger values higher
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Largest;
+
+/// Ranks smaller values higher
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Smallest;
+
+/// Ranks values with larger keys higher. Wrap the key in [`std::cmp::Reverse`] to prefer smaller
+/// ones.
+#[derive(Clone, Copy, Debug)]
+pub struct ByKey<F>(pub F);
+
+impl<T : Ord> Rank<T> for Largest {
+    fn compare(&self, a : &T, b : &T) -> Ordering { a.cmp(b) }
+}
+
+impl<T : Ord> Rank<T> for Smallest {
+    fn compare(&self, a : &T, b : &T) -> Ordering { b.cmp(a) }
+}
+
+impl<T, K : Ord, F : Fn(&T) -> K> Rank<T> for ByKey<F> {
+    fn compare(&self, a : &T, b : &T) -> Ordering { (self.0)(a).cmp(&(self.0)(b)) }
+}
+
+/// Keeps the `K` highest ranking of the values pushed into it, by default the largest ones. Of
+/// equally ranked values, the ones pushed first are kept.
+///
+/// The values are kept in a binary heap of at most `K` entries with the lowest ranking one on top,
+/// so every push takes O(log K).
+///
+/// `K` is part of the type instead of a capacity given to a constructor, so [`Default`] and
+/// [`FromIterator`] know how many values to keep, as in `let top : TopK<u32, 3> = it.collect();`.
+#[derive(Clone, Debug)]
+pub struct TopK<T, const K : usize, R = Largest> {
+    heap : Vec<T>,
+    rank : R
+}
+
+impl<T, const K : usize> TopK<T, K, Largest> {
+    pub fn largest() -> TopK<T, K, Largest> { TopK::with_rank(Largest) }
+}
+
+impl<T, const K : usize> TopK<T, K, Smallest> {
+    pub fn smallest() -> TopK<T, K, Smallest> { TopK::with_rank(Smallest) }
+}
+
+impl<T, const K : usize, F> TopK<T, K, ByKey<F>> {
+    pub fn by_key(key : F) -> TopK<T, K, ByKey<F>> { TopK::with_rank(ByKey(key)) }
+}
+
+impl<T, const K : usize, R : Default> Default for TopK<T, K, R> {
+    fn default() -> Self { TopK::with_rank(R::default()) }
+}
+
+impl<T, const K : usize, R> TopK<T, K, R> {
+    pub fn with_rank(rank : R) -> TopK<T, K, R> {
+        TopK{heap: Vec::with_capacity(K), rank: rank}
+    }
+
+    pub fn len(&self) -> usize { self.heap.len() }
+    pub fn is_empty(&self) -> bool { self.heap.is_empty() }
+
+    /// The kept values in no particular order
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.heap.iter()
+    }
+}
+
+impl<T, const K : usize, R : Rank<T>> TopK<T, K, R> {
+    pub fn push(&mut self, value : T) {
+        if self.heap.len() < K {
+            self.heap.push(value);
+            self.sift_up(self.heap.len() - 1);
+        } else if K > 0 && self.ranks_lower(0, &value) {
+            self.heap[0] = value;
+            self.sift_down(0);
+        }
+    }
+
+    /// The kept values, highest ranking first
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let mut values = self.heap;
+        let rank = self.rank;
+        values.sort_by(|a, b| rank.compare(b, a));
+        return values;
+    }
+
+    // Whether the entry at `index` ranks lower than `value`
+    fn ranks_lower(&self, index : usize, value : &T) -> bool {
+        self.rank.compare(&self.heap[index], value) == Ordering::Less
+    }
+
+    fn sift_up(&mut self, mut index : usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !self.ranks_lower(index, &self.heap[parent]) {
+                break;
+            }
+            self.heap.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index : usize) {
+        loop {
+            let mut lowest = index;
+            for child in [2*index + 1, 2*index + 2] {
+                if child < self.heap.len() && self.ranks_lower(child, &self.heap[lowest]) {
+                    lowest = child;
+                }
+            }
+            if lowest == index {
+                break;
+            }
+            self.heap.swap(index, lowest);
+            index = lowest;
+        }
+    }
+}
+
+impl<T, const K : usize, R : Rank<T>> Extend<T> for TopK<T, K, R> {
+    fn extend<I : IntoIterator<Item = T>>(&mut self, values : I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
+
+impl<T, const K : usize, R : Rank<T> + Default> FromIterator<T> for TopK<T, K, R> {
+    fn from_iter<I : IntoIterator<Item = T>>(values : I) -> Self {
+        let mut top = TopK::default();
+        top.extend(values);
+        return top;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static VALUES : [u32; 7] = [5, 1, 9, 3, 7, 1, 8];
+
+    #[test]
+    fn keeps_the_largest() {
+        let mut top = TopK::<u32, 3>::largest();
+        top.extend(VALUES);
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.into_sorted_vec(), [9, 8, 7]);
+    }
+
+    #[test]
+    fn keeps_the_smallest() {
+        let mut top = TopK::<u32, 3, Smallest>::smallest();
+        top.extend(VALUES);
+        assert_eq!(top.into_sorted_vec(), [1, 1, 3]);
+        let top : TopK<u32, 1, Smallest> = VALUES.into_iter().collect();
+        assert_eq!(top.into_sorted_vec(), [1]);
+    }
+
+    #[test]
+    fn keeps_everything_below_k() {
+        let top : TopK<u32, 10> = VALUES.into_iter().collect();
+        assert_eq!(top.into_sorted_vec(), [9, 8, 7, 5, 3, 1, 1]);
+        let none : TopK<u32, 0> = VALUES.into_iter().collect();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn ties_keep_the_values_pushed_first() {
+        let mut top = TopK::<(u32, char), 2, _>::by_key(|(key, _) : &(u32, char)| *key);
+        top.extend([(1, 'a'), (2, 'b'), (2, 'c'), (2, 'd'), (0, 'e')]);
+        let mut kept : Vec<char> = top.iter().map(|(_, name)| *name).collect();
+        kept.sort();
+        assert_eq!(kept, ['b', 'c']);
+
+        let mut top = TopK::<(u32, char), 2, _>::by_key(|(key, _) : &(u32, char)| std::cmp::Reverse(*key));
+        top.extend([(3, 'a'), (1, 'b'), (1, 'c'), (1, 'd')]);
+        let mut kept : Vec<char> = top.iter().map(|(_, name)| *name).collect();
+        kept.sort();
+        assert_eq!(kept, ['b', 'c']);
+    }
+}
@@ -0,0 +1,83 @@
+// Times the parse and both parts of every door, once on its real input and, for the doors where
+// the input size matters, on synthetic inputs far larger than the puzzle ones.
+//
+// Criterion keeps the results of the last run in target/criterion and reports the change against
+// them. To compare against a fixed state instead, save it under a name first:
+//
+//     cargo bench -p aoc --bench doors -- --save-baseline before
+//     cargo bench -p aoc --bench doors -- --baseline before
+//
+// Append a filter like `door09` to only run some of the benchmarks. The synthetic inputs are the
+// same as `aoc gen --day <n> --seed 0 --size <size>`.
+
+use std::fs;
+use std::path::PathBuf;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use aoc_common::generator::Generator;
+use aoc_common::parse::Cursor;
+use aoc_common::rng::Rng;
+use aoc_common::solution::Solution;
+
+fn bench<S : Solution>(c : &mut Criterion, name : &str, contents : &str, samples : usize) {
+    let input = S::parse(&mut Cursor::new(contents.split("\n")))
+        .unwrap_or_else(|err| panic!("{}:{}", name, err));
+
+    let mut group = c.benchmark_group(name);
+    group.sample_size(samples);
+    group.bench_function("parse", |b| b.iter(|| {
+        S::parse(&mut Cursor::new(black_box(contents).split("\n"))).is_ok()
+    }));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    group.finish();
+}
+
+fn real<S : Solution>(c : &mut Criterion, door : &str) {
+    let file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(door).join("input.txt");
+    let contents = fs::read_to_string(&file)
+        .unwrap_or_else(|err| panic!("{}: {}", file.display(), err));
+    bench::<S>(c, &format!("{}/input.txt", door.to_lowercase()), &contents, 100);
+}
+
+// `cargo test` runs every benchmark once on an unoptimized build to check that it still works,
+// the synthetic inputs are kept small there
+fn scaled(full : usize) -> usize {
+    if cfg!(debug_assertions) { full / 100 } else { full }
+}
+
+fn generated<S : Solution + Generator>(c : &mut Criterion, door : &str, size : usize) {
+    let size = scaled(size);
+    let mut contents = vec!();
+    S::generate(&mut Rng::new(0), size, &mut contents).unwrap();
+    let contents = String::from_utf8(contents).unwrap();
+    bench::<S>(c, &format!("{}/generated-{}", door, size), &contents, 10);
+}
+
+fn real_inputs(c : &mut Criterion) {
+    real::<door01::Door01>(c, "Door01");
+    real::<door02::Door02>(c, "Door02");
+    real::<door03::Door03>(c, "Door03");
+    real::<door04::Door04>(c, "Door04");
+    real::<door05::Door05>(c, "Door05");
+    real::<door06::Door06>(c, "Door06");
+    real::<door07::Door07>(c, "Door07");
+    real::<door08::Door08>(c, "Door08");
+    real::<door09::Door09>(c, "Door09");
+    real::<door10::Door10>(c, "Door10");
+    real::<door11::Door11>(c, "Door11");
+    real::<door12::Door12>(c, "Door12");
+    real::<door13::Door13>(c, "Door13");
+    real::<door14::Door14>(c, "Door14");
+}
+
+fn synthetic_inputs(c : &mut Criterion) {
+    generated::<door09::Door09>(c, "door09", 2000);
+    generated::<door10::Door10>(c, "door10", 100_000);
+    generated::<door05::Door05>(c, "door05", 1_000_000);
+    generated::<door12::Door12>(c, "door12", 100_000);
+}
+
+criterion_group!(benches, real_inputs, synthetic_inputs);
+criterion_main!(benches);
@@ -0,0 +1,236 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process;
+use std::time::Instant;
+
+use aoc_common::generator::Generator;
+use aoc_common::input;
+use aoc_common::parse;
+use aoc_common::present;
+use aoc_common::render;
+use aoc_common::rng::Rng;
+use aoc_common::solution::Solution;
+
+static USAGE : &str = "Usage: aoc run --day <1-14> [--part <1|2>] [--input <file|->] [--render <file.png|->]
+       aoc gen --day <1-14> [--seed <n>] [--size <n>] [--output <file|->]";
+
+enum Command {
+    Run { day : u32, part : Option<u32>, input : String, render : Option<String> },
+    Gen { day : u32, seed : u64, size : Option<usize>, output : String }
+}
+
+fn parse_options(args : &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    let command = match args.next().map(String::as_str) {
+        Some(command @ ("run" | "gen")) => command,
+        Some(other) => { return Err(format!("Unknown command {}", other)); },
+        None => { return Err(String::from("Missing command")); }
+    };
+
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut render = None;
+    let mut seed = None;
+    let mut size = None;
+    let mut output = None;
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("Missing value for {}", flag))?;
+        match (command, flag.as_str()) {
+            (_, "--day") => {
+                day = Some(value.parse::<u32>().map_err(|_| format!("Invalid day {}", value))?);
+            },
+            ("run", "--part") => {
+                part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => { return Err(format!("Invalid part {}", value)); }
+                };
+            },
+            ("run", "--input") => { input = Some(value.clone()); },
+            ("run", "--render") => { render = Some(value.clone()); },
+            ("gen", "--seed") => {
+                seed = Some(value.parse::<u64>().map_err(|_| format!("Invalid seed {}", value))?);
+            },
+            ("gen", "--size") => {
+                size = Some(value.parse::<usize>().map_err(|_| format!("Invalid size {}", value))?);
+            },
+            ("gen", "--output") => { output = Some(value.clone()); },
+            _ => { return Err(format!("Unknown option {}", flag)); }
+        }
+    }
+
+    let day = day.ok_or("Missing --day")?;
+    if command == "gen" {
+        let output = output.unwrap_or_else(|| String::from("-"));
+        return Ok(Command::Gen{day: day, seed: seed.unwrap_or(0), size: size, output: output});
+    }
+    let input = input.unwrap_or_else(|| format!("Door{:02}/input.txt", day));
+    return Ok(Command::Run{day: day, part: part, input: input, render: render});
+}
+
+/// Calls the generic function `f` with the door of the given day, or returns `None` if the day is
+/// not solved yet
+macro_rules! with_door {
+    ($day:expr, $f:ident($($arg:expr),*)) => {
+        match $day {
+            1 => Some($f::<door01::Door01>($($arg),*)),
+            2 => Some($f::<door02::Door02>($($arg),*)),
+            3 => Some($f::<door03::Door03>($($arg),*)),
+            4 => Some($f::<door04::Door04>($($arg),*)),
+            5 => Some($f::<door05::Door05>($($arg),*)),
+            6 => Some($f::<door06::Door06>($($arg),*)),
+            7 => Some($f::<door07::Door07>($($arg),*)),
+            8 => Some($f::<door08::Door08>($($arg),*)),
+            9 => Some($f::<door09::Door09>($($arg),*)),
+            10 => Some($f::<door10::Door10>($($arg),*)),
+            11 => Some($f::<door11::Door11>($($arg),*)),
+            12 => Some($f::<door12::Door12>($($arg),*)),
+            13 => Some($f::<door13::Door13>($($arg),*)),
+            14 => Some($f::<door14::Door14>($($arg),*)),
+            _ => None
+        }
+    };
+}
+
+// Pixels per entry in rendered images
+static SCALE : usize = 8;
+
+fn run<S : Solution>(reader : Box<dyn BufRead>, part : Option<u32>, render : Option<&str>) -> Result<(), parse::ParseError> {
+    let start = Instant::now();
+    let mut cursor = parse::Cursor::from_reader(reader);
+    let input = S::parse(&mut cursor)?;
+    println!("Parse: ({:?})", start.elapsed());
+
+    if part != Some(2) {
+        let start = Instant::now();
+        match S::part1(&input) {
+            Ok(answer) => present::answer("Part 1", &answer, Some(start.elapsed())),
+            Err(err) => {
+                eprintln!("Part 1: {}", err);
+                process::exit(1);
+            }
+        }
+    }
+    if part != Some(1) {
+        let start = Instant::now();
+        match S::part2(&input) {
+            Ok(answer) => present::answer("Part 2", &answer, Some(start.elapsed())),
+            Err(err) => {
+                eprintln!("Part 2: {}", err);
+                process::exit(1);
+            }
+        }
+    }
+    if let Some(target) = render {
+        let Some(image) = S::render(&input) else {
+            eprintln!("This day has nothing to render");
+            process::exit(2);
+        };
+        let result = if target == "-" {
+            io::stdout().write_all(render::ansi(&image).as_bytes())
+        } else {
+            File::create(target).and_then(|file| render::png(&image, SCALE, BufWriter::new(file)))
+        };
+        if let Err(err) = result {
+            eprintln!("{}: {}", target, err);
+            process::exit(1);
+        }
+    }
+    return Ok(());
+}
+
+fn generate<G : Generator>(seed : u64, size : Option<usize>, out : & mut dyn Write) -> io::Result<()> {
+    let mut rng = Rng::new(seed);
+    G::generate(&mut rng, size.unwrap_or(G::SIZE), out)?;
+    return out.flush();
+}
+
+fn unsolved(day : u32) -> ! {
+    eprintln!("Day {} is not solved yet\n{}", day, USAGE);
+    process::exit(2);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = match parse_options(&args) {
+        Ok(command) => command,
+        Err(msg) => {
+            eprintln!("{}\n{}", msg, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Run{day, part, input, render} => {
+            let reader = match input::open(&input) {
+                Ok(reader) => reader,
+                Err(err) => {
+                    eprintln!("{}: {}", input, err);
+                    process::exit(1);
+                }
+            };
+            let result = with_door!(day, run(reader, part, render.as_deref())).unwrap_or_else(|| unsolved(day));
+            if let Err(err) = result {
+                eprintln!("{}:{}", input, err);
+                process::exit(1);
+            }
+        },
+        Command::Gen{day, seed, size, output} => {
+            let out : io::Result<Box<dyn Write>> = if output == "-" {
+                Ok(Box::new(BufWriter::new(io::stdout().lock())))
+            } else {
+                File::create(&output).map(|file| Box::new(BufWriter::new(file)) as Box<dyn Write>)
+            };
+            let result = out.and_then(|mut out| {
+                with_door!(day, generate(seed, size, &mut out)).unwrap_or_else(|| unsolved(day))
+            });
+            if let Err(err) = result {
+                eprintln!("{}: {}", output, err);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args : &str) -> Result<Command, String> {
+        let args : Vec<String> = args.split_whitespace().map(String::from).collect();
+        parse_options(&args)
+    }
+
+    #[test]
+    fn run_options() {
+        let Ok(Command::Run{day, part, input, render}) = parse("run --day 5 --part 2 --input vents.txt --render vents.png") else { panic!() };
+        assert_eq!((day, part, input.as_str(), render.as_deref()), (5, Some(2), "vents.txt", Some("vents.png")));
+        let Ok(Command::Run{day, part, input, render}) = parse("run --day 7") else { panic!() };
+        assert_eq!((day, part, input.as_str(), render), (7, None, "Door07/input.txt", None));
+    }
+
+    #[test]
+    fn gen_options() {
+        let Ok(Command::Gen{day, seed, size, output}) = parse("gen --day 4 --seed 9 --size 20 --output bingo.txt") else { panic!() };
+        assert_eq!((day, seed, size, output.as_str()), (4, 9, Some(20), "bingo.txt"));
+        let Ok(Command::Gen{day, seed, size, output}) = parse("gen --day 12") else { panic!() };
+        assert_eq!((day, seed, size, output.as_str()), (12, 0, None, "-"));
+    }
+
+    #[test]
+    fn invalid_options() {
+        assert_eq!(parse("").err().unwrap(), "Missing command");
+        assert_eq!(parse("walk --day 1").err().unwrap(), "Unknown command walk");
+        assert_eq!(parse("run --part 1").err().unwrap(), "Missing --day");
+        assert_eq!(parse("run --day x").err().unwrap(), "Invalid day x");
+        assert_eq!(parse("run --day 1 --part 3").err().unwrap(), "Invalid part 3");
+        assert_eq!(parse("run --day 1 --part").err().unwrap(), "Missing value for --part");
+        assert_eq!(parse("run --day 1 --verbose yes").err().unwrap(), "Unknown option --verbose");
+        assert_eq!(parse("run --day 1 --seed 3").err().unwrap(), "Unknown option --seed");
+        assert_eq!(parse("gen --day 1 --part 1").err().unwrap(), "Unknown option --part");
+        assert_eq!(parse("gen --day 1 --render -").err().unwrap(), "Unknown option --render");
+        assert_eq!(parse("gen --day 1 --size -5").err().unwrap(), "Invalid size -5");
+    }
+}
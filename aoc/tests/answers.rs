@@ -0,0 +1,75 @@
+// Runs every door against the inputs listed in its answers.toml and compares the results with the
+// expected answers. Numeric answers may be written as TOML integers, everything else as strings.
+
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_common::input;
+use aoc_common::parse;
+use aoc_common::solution::Solution;
+
+fn expected(fixture : &toml::Table, part : &str) -> Option<String> {
+    match fixture.get(part)? {
+        toml::Value::String(answer) => Some(answer.clone()),
+        toml::Value::Integer(answer) => Some(answer.to_string()),
+        other => panic!("Unsupported answer {}", other)
+    }
+}
+
+fn check<S : Solution>(door : &str) {
+    let directory = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(door);
+    let answers = fs::read_to_string(directory.join("answers.toml"))
+        .unwrap_or_else(|err| panic!("{}/answers.toml: {}", door, err));
+    let fixtures : toml::Table = toml::from_str(&answers)
+        .unwrap_or_else(|err| panic!("{}/answers.toml: {}", door, err));
+    assert!(!fixtures.is_empty(), "{}/answers.toml lists no inputs", door);
+
+    for (file, fixture) in &fixtures {
+        let fixture = fixture.as_table()
+            .unwrap_or_else(|| panic!("{}/answers.toml: {} is not a table", door, file));
+        let path = directory.join(file);
+        let reader = input::open(path.to_str().unwrap())
+            .unwrap_or_else(|err| panic!("{}/{}: {}", door, file, err));
+        let mut cursor = parse::Cursor::from_reader(reader);
+        let input = S::parse(&mut cursor)
+            .unwrap_or_else(|err| panic!("{}/{}:{}", door, file, err));
+        if let Some(answer) = expected(fixture, "part1") {
+            let actual = S::part1(&input)
+                .unwrap_or_else(|err| panic!("{}/{} part 1: {}", door, file, err));
+            assert_eq!(actual.to_string(), answer, "{}/{} part 1", door, file);
+        }
+        if let Some(answer) = expected(fixture, "part2") {
+            let actual = S::part2(&input)
+                .unwrap_or_else(|err| panic!("{}/{} part 2: {}", door, file, err));
+            assert_eq!(actual.to_string(), answer, "{}/{} part 2", door, file);
+        }
+    }
+}
+
+macro_rules! answers {
+    ($($name:ident : $door:literal => $solution:ty),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check::<$solution>($door);
+            }
+        )*
+    };
+}
+
+answers! {
+    door01: "Door01" => door01::Door01,
+    door02: "Door02" => door02::Door02,
+    door03: "Door03" => door03::Door03,
+    door04: "Door04" => door04::Door04,
+    door05: "Door05" => door05::Door05,
+    door06: "Door06" => door06::Door06,
+    door07: "Door07" => door07::Door07,
+    door08: "Door08" => door08::Door08,
+    door09: "Door09" => door09::Door09,
+    door10: "Door10" => door10::Door10,
+    door11: "Door11" => door11::Door11,
+    door12: "Door12" => door12::Door12,
+    door13: "Door13" => door13::Door13,
+    door14: "Door14" => door14::Door14,
+}
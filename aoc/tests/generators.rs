@@ -0,0 +1,64 @@
+// Generates inputs of the real size for every door with a few seeds and checks that the door
+// parses and solves them, with or without a final newline, and that a seed always gives the same
+// input.
+
+use aoc_common::generator::Generator;
+use aoc_common::parse::Cursor;
+use aoc_common::rng::Rng;
+use aoc_common::solution::Solution;
+
+static SEEDS : [u64; 3] = [0, 1, 2021];
+
+fn generate<G : Generator>(seed : u64) -> String {
+    let mut out = vec!();
+    G::generate(&mut Rng::new(seed), G::SIZE, &mut out).unwrap();
+    return String::from_utf8(out).unwrap();
+}
+
+// Both answers as text, or the step that failed
+fn solve<S : Solution>(contents : &str) -> Result<(String, String), String> {
+    let input = S::parse(&mut Cursor::new(contents.split("\n"))).map_err(|err| format!("parse:{}", err))?;
+    let part1 = S::part1(&input).map_err(|err| format!("part 1: {}", err))?;
+    let part2 = S::part2(&input).map_err(|err| format!("part 2: {}", err))?;
+    return Ok((part1.to_string(), part2.to_string()));
+}
+
+fn check<S : Solution + Generator>(door : &str) {
+    for seed in SEEDS {
+        let contents = generate::<S>(seed);
+        assert_eq!(contents, generate::<S>(seed), "{} seed {} is not deterministic", door, seed);
+        let answers = solve::<S>(&contents).unwrap_or_else(|err| panic!("{} seed {} {}", door, seed, err));
+        // Files usually end in a newline, which the generators leave out
+        let with_newline = solve::<S>(&format!("{}\n", contents)).unwrap_or_else(|err| panic!("{} seed {} with a final newline {}", door, seed, err));
+        assert_eq!(answers, with_newline, "{} seed {} changes with a final newline", door, seed);
+    }
+    assert_ne!(generate::<S>(SEEDS[0]), generate::<S>(SEEDS[1]), "{} ignores the seed", door);
+}
+
+macro_rules! generators {
+    ($($name:ident : $door:literal => $solution:ty),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check::<$solution>($door);
+            }
+        )*
+    };
+}
+
+generators! {
+    door01: "Door01" => door01::Door01,
+    door02: "Door02" => door02::Door02,
+    door03: "Door03" => door03::Door03,
+    door04: "Door04" => door04::Door04,
+    door05: "Door05" => door05::Door05,
+    door06: "Door06" => door06::Door06,
+    door07: "Door07" => door07::Door07,
+    door08: "Door08" => door08::Door08,
+    door09: "Door09" => door09::Door09,
+    door10: "Door10" => door10::Door10,
+    door11: "Door11" => door11::Door11,
+    door12: "Door12" => door12::Door12,
+    door13: "Door13" => door13::Door13,
+    door14: "Door14" => door14::Door14,
+}